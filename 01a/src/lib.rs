@@ -0,0 +1,72 @@
+use std::error::Error;
+
+use aoc2023::parse::{each_line_in, is_strict, ParseError, Span};
+use aoc2023::Solver;
+
+pub fn parse_first_and_last_digit(input: &str) -> Result<Vec<String>, ParseError> {
+    parse_first_and_last_digit_in(input, is_strict())
+}
+
+pub fn parse_first_and_last_digit_in(input: &str, strict: bool) -> Result<Vec<String>, ParseError> {
+    each_line_in(Span::new(input), strict, |line| {
+        let digits: Vec<char> = line.chars().filter(|c| c.is_numeric()).collect();
+        match (digits.first(), digits.last()) {
+            (Some(first_digit), Some(last_digit)) => Ok(format!("{}{}", first_digit, last_digit)),
+            _ => Err(line.error("a digit")),
+        }
+    })
+}
+
+pub fn calculate_sum(results: Vec<String>) -> Result<String, Box<dyn Error>> {
+    let sum = results
+        .iter()
+        .map(|s| s.parse::<i32>())
+        .collect::<Result<Vec<i32>, _>>()?
+        .iter()
+        .sum::<i32>();
+
+    Ok(sum.to_string())  // Convert the sum back to a String for output
+}
+
+pub struct Day01a;
+
+impl Solver for Day01a {
+    const DAY: u8 = 1;
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_first_and_last_digit(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Box<dyn Error>> {
+        calculate_sum(input.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_first_and_last() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+        let expected = vec!["12".to_string(), "38".to_string(), "15".to_string(), "77".to_string()];
+        let result = parse_first_and_last_digit(input).unwrap();
+        assert_eq!(result, expected);
+
+        // a line without a digit has no calibration value
+        let err = parse_first_and_last_digit_in("1abc2\nabc", true).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "abc"));
+        assert_eq!(parse_first_and_last_digit_in("1abc2\nabc", false).unwrap(), ["12"]);
+    }
+
+    #[test]
+    fn test_calculate_sum() {
+        let input = vec!["12".to_string(), "38".to_string(), "15".to_string(), "77".to_string()];
+        let expected_sum = "142"; // expected sum of 12 + 38 + 15 + 77
+
+        let result = calculate_sum(input).unwrap();
+        assert_eq!(result, expected_sum);
+    }
+}
@@ -0,0 +1,120 @@
+use std::error::Error;
+
+use aoc2023::parse::{each_line_in, is_strict, ParseError, Span};
+use aoc2023::Solver;
+
+const NUMS: [(&[u8], char); 9] = [
+    (b"one", '1'), (b"two", '2'), (b"three", '3'), (b"four", '4'), 
+    (b"five", '5'), (b"six", '6'), (b"seven", '7'), (b"eight", '8'), 
+    (b"nine", '9'),
+];
+
+
+pub fn parse_first_and_last_digit(input: &str) -> Result<Vec<String>, ParseError> {
+    parse_first_and_last_digit_in(input, is_strict())
+}
+
+pub fn parse_first_and_last_digit_in(input: &str, strict: bool) -> Result<Vec<String>, ParseError> {
+    // Process each line to extract first and last digits
+    each_line_in(Span::new(input), strict, |line| {
+        // Extract first number
+        let first_digit = (0..line.len())
+            .find_map(|i| num(line.as_bytes(), i))
+            .ok_or_else(|| line.error("a digit or a spelled out one"))?;
+
+        // Extract last number with reversed loop
+        let last_digit = (0..line.len())
+            .rev()
+            .find_map(|i| num(line.as_bytes(), i))
+            .ok_or_else(|| line.error("a digit or a spelled out one"))?;
+
+        // Combine first and last digits into a string
+        Ok(format!("{}{}", first_digit, last_digit))
+    })
+}
+
+
+/// Extracts a numeric value from a byte slice.
+///
+/// Given a `line` of bytes and an index `i`, this function looks for numeric values
+/// in the `line`. It first checks if the character at index `i` is an ASCII digit.
+/// If so, it converts it to an integer and returns it. If not, it searches for
+/// matches in `NUM_MAP` to find corresponding numeric values for words in the `line`.
+/// It returns the numeric value found, or `None` if no numeric value is detected.
+///
+/// # Arguments
+///
+/// * `line` - The byte slice containing the input line.
+/// * `i` - The index in the byte slice where the search for a numeric value begins.
+///
+/// # Returns
+///
+/// * `Some(usize)` - If a numeric value is found, it returns the numeric value as `Some`.
+/// * `None` - If no numeric value is found, it returns `None`.
+#[inline(always)]
+fn num(line: &[u8], i: usize) -> Option<usize> {
+    line[i]
+        .is_ascii_digit()
+        .then_some((line[i] - b'0') as usize)
+        .or(NUMS
+            .iter()
+            .enumerate()
+            .find(|(_, name)| line[i..].starts_with(name.0))
+            .map(|(num, _)| num + 1))
+}
+
+pub fn calculate_sum(numbers: &[String]) -> Result<usize, Box<dyn Error>> {
+    let sum: usize = numbers
+        .iter()
+        .map(|s| s.parse::<usize>())
+        .collect::<Result<Vec<usize>, _>>()?
+        .iter()
+        .sum();
+
+    Ok(sum)
+}
+
+pub struct Day01b;
+
+impl Solver for Day01b {
+    const DAY: u8 = 1;
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_first_and_last_digit(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Box<dyn Error>> {
+        Ok(calculate_sum(input)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_first_and_last() {
+        let input = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen";
+        // Expected: 29, 83, 13, 24, 42, 14, and 76.
+        let expected = vec!["29".to_string(), "83".to_string(), "13".to_string(), "24".to_string(), "42".to_string(), "14".to_string(), "76".to_string()];
+        let result = parse_first_and_last_digit(input).unwrap();
+        assert_eq!(result, expected);
+
+        let err = parse_first_and_last_digit_in("two1nine\nabc", true).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 1, "abc"));
+        assert_eq!(parse_first_and_last_digit_in("two1nine\nabc", false).unwrap(), ["29"]);
+    }
+
+    #[test]
+    fn test_calculate_sum() {
+        let input = vec!["29".to_string(), "83".to_string(), "13".to_string(), "24".to_string(), "42".to_string(), "14".to_string(), "76".to_string()];
+        // usize expected_sum
+        let expected_sum = 281; // expected sum of 29, 83, 13, 24, 42, 14, and 76.
+        let result = calculate_sum(&input).unwrap();
+        println!("{}", result);
+
+        assert_eq!(result, expected_sum);
+    }
+}
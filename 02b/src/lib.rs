@@ -0,0 +1,94 @@
+use std::error::Error;
+
+use aoc2023::parse::{each_line_in, integer, is_strict, labelled, record, ParseError, Span};
+use aoc2023::Solver;
+
+#[derive(Clone)]
+pub struct GameData {
+    pub red: Vec<usize>,
+    pub green: Vec<usize>,
+    pub blue: Vec<usize>,
+}
+
+pub fn parse_data(input: &str) -> Result<Vec<GameData>, Box<dyn Error>> {
+    Ok(parse_data_in(input, is_strict())?)
+}
+
+pub fn parse_data_in(input: &str, strict: bool) -> Result<Vec<GameData>, ParseError> {
+    each_line_in(Span::new(input), strict, parse_game)
+}
+
+// Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+fn parse_game(line: Span) -> Result<GameData, ParseError> {
+    let (head, sets) = line.split_once(":")?;
+    integer::<usize>(labelled(head, "Game")?)?;
+
+    let mut red = Vec::new();
+    let mut green = Vec::new();
+    let mut blue = Vec::new();
+
+    for set in sets.split(";") {
+        let (mut r, mut g, mut b) = (0, 0, 0);
+        for (count, color) in record(set, ",", " ")? {
+            let count = integer(count)?;
+            match color.as_str() {
+                "red" => r = count,
+                "green" => g = count,
+                "blue" => b = count,
+                _ => return Err(color.error("red, green or blue")),
+            }
+        }
+        red.push(r);
+        green.push(g);
+        blue.push(b);
+    }
+
+    Ok(GameData { red, green, blue })
+}
+
+// we search largest number of cubes in each color array and multiply them together
+pub fn fewest_cubes(games: &[GameData]) -> Vec<usize> {
+    games.iter()
+        .map(|game| {
+            let max_red = *game.red.iter().max().unwrap_or(&0);
+            let max_green = *game.green.iter().max().unwrap_or(&0);
+            let max_blue = *game.blue.iter().max().unwrap_or(&0);
+            max_red * max_green * max_blue
+        })
+        .collect()
+}
+
+pub struct Day02b;
+
+impl Solver for Day02b {
+    const DAY: u8 = 2;
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+    type Input = Vec<GameData>;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        parse_data(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Box<dyn Error>> {
+        Ok(fewest_cubes(input).iter().sum::<usize>().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_game_data_from_string(input: &str) -> Vec<GameData> {
+        parse_data(input).expect("Failed to parse data")
+    }
+
+    #[test]
+    fn test_fewest_cubes() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\nGame 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\nGame 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\nGame 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        let games = create_game_data_from_string(input);
+        let game_points = fewest_cubes(&games);
+        let sum = game_points.iter().sum::<usize>();
+        let expected = 2286; // 48, 12, 1560, 630, 36 = 2286
+        assert_eq!(sum, expected);
+    }
+}
@@ -0,0 +1,113 @@
+use std::error::Error;
+
+use aoc2023::grid::{Grid, Point};
+use aoc2023::parse::ParseError;
+use aoc2023::Solver;
+use tracing::{debug, trace};
+
+pub struct EngineSchematic {
+    grid: Grid<char>,
+}
+
+impl EngineSchematic {
+    // implement the constructor, always strict whatever the mode: skipping a
+    // line that doesn't fit the grid would shift every number below it
+    pub fn new(input: &str) -> Result<EngineSchematic, ParseError> {
+        Ok(EngineSchematic { grid: input.parse()? })
+    }
+
+    // sum up all numbers that don't have a symbol adjacent to it
+    pub fn sum_of_part_numbers(&self) -> u64 {
+        let mut sum = 0;
+        let mut seen = self.grid.map(|_| false);
+
+        for (start, &cell) in self.grid.iter() {
+            // if decimal digit and not seen yet
+            if cell.is_ascii_digit() && !seen[start] {
+                let number = self.extract_number(start, &mut seen);
+                if self.is_number_adjacent_to_symbol(start, &number) {
+                    debug!("part number {} at {}", number, start);
+                    sum += number.parse::<u64>().unwrap();
+                }
+            }
+        }
+        sum
+    }
+
+    // Implement the method to extract the full number starting from a digit
+    // Also, mark the cells of this number as seen to avoid double-counting
+    fn extract_number(&self, start: Point, seen: &mut Grid<bool>) -> String {
+        trace!("extracting number at {} (width {})", start, self.grid.width());
+        let mut number = String::new();
+
+        for (p, &cell) in self.grid.ray(start, Point::RIGHT).take_while(|(_, c)| c.is_ascii_digit()) {
+            seen[p] = true;
+            number.push(cell);
+        }
+
+        trace!("extracted {}", number);
+        number
+    }
+
+    // Implement the method to check if a number is adjacent to a symbol so we won't sum it
+    fn is_number_adjacent_to_symbol(&self, start: Point, number: &str) -> bool {
+        trace!("numlength: {}", number.len());
+
+        // Iterate through each character in the number
+        for offset in 0..number.len() {
+            let p = start + Point::new(offset as i64, 0);
+            trace!("col: {}", p.x);
+
+            // Check all neighbours on the grid for special symbols
+            for (_, &symbol) in self.grid.neighbours8(p) {
+                if symbol.is_ascii_punctuation() && symbol != '.' {
+                    trace!("{} is adjacent to a {}", symbol, number);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+pub struct Day03a;
+
+impl Solver for Day03a {
+    const DAY: u8 = 3;
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+    type Input = EngineSchematic;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(EngineSchematic::new(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Box<dyn Error>> {
+        Ok(input.sum_of_part_numbers().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // calculate all the points
+    // create grid to find points that needs to be deducted
+    // deduct points from all points
+    #[test]
+    fn test_game_points() {
+        let input = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+
+        // Create an instance of EngineSchematic
+        let schematic = EngineSchematic::new(input).unwrap();
+
+        // Call the sum_of_part_numbers method
+        let score = schematic.sum_of_part_numbers();
+
+        let expected = 4361; // 467+35+633+617+592+755+664+598+58+114-58-114 = 4361
+        assert_eq!(score, expected);
+
+        // there is no lenient grid, an empty or ragged line is always an error
+        assert!(EngineSchematic::new("467..\n\n..35.").is_err());
+        assert!(EngineSchematic::new("467..\n..35").is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+use std::error::Error;
+
+use aoc2023::grid::{Grid, Point};
+use aoc2023::parse::ParseError;
+use aoc2023::Solver;
+
+pub struct EngineSchematic {
+    grid: Grid<char>,
+}
+
+impl EngineSchematic {
+    // implement the constructor, always strict whatever the mode: skipping a
+    // line that doesn't fit the grid would shift every number below it
+    pub fn new(input: &str) -> Result<EngineSchematic, ParseError> {
+        Ok(EngineSchematic { grid: input.parse()? })
+    }
+
+    // New method to calculate the sum of gear ratios
+    pub fn sum_of_gear_ratios(&self) -> u32 {
+        let mut sum = 0;
+        let mut seen = self.grid.map(|_| false);
+
+        for gear in self.grid.find_all(|&cell| cell == '*') {
+            // Find and multiply adjacent part numbers
+            if let Some(gear_ratio) = self.find_and_multiply_adjacent_parts(gear, &mut seen) {
+                sum += gear_ratio;
+            }
+        }
+        sum
+    }
+
+    // Method to find and multiply adjacent part numbers to a '*'
+    fn find_and_multiply_adjacent_parts(&self, gear: Point, seen: &mut Grid<bool>) -> Option<u32> {
+        let mut part_numbers = HashSet::new();
+
+        // we should measure whole length of the number to be around the '*'
+        for (p, cell) in self.grid.neighbours8(gear) {
+            if cell.is_ascii_digit() && !seen[p] {
+                let number = self.extract_number(p, seen);
+                part_numbers.insert(number.parse::<u16>().unwrap_or(0));
+            }
+        }
+
+        let parts = part_numbers.into_iter().collect::<Vec<_>>();
+        if parts.len() == 2 {
+            tracing::debug!("gear at {}: {} * {}", gear, parts[0], parts[1]);
+            Some(parts[0] as u32 * parts[1] as u32)
+        } else {
+            None
+        }
+    }
+
+    fn extract_number(&self, p: Point, seen: &mut Grid<bool>) -> String {
+        let is_digit = |(_, c): &(Point, &char)| c.is_ascii_digit();
+
+        // Move to beginning in the matched number string
+        let start = self.grid.ray(p, Point::LEFT).take_while(is_digit).last().map_or(p, |(q, _)| q);
+
+        // Now move right to extract the entire number string
+        let mut number = String::new();
+        for (q, &cell) in self.grid.ray(start, Point::RIGHT).take_while(is_digit) {
+            seen[q] = true;
+            number.push(cell);
+        }
+        number
+    }
+}
+
+pub struct Day03b;
+
+impl Solver for Day03b {
+    const DAY: u8 = 3;
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+    type Input = EngineSchematic;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(EngineSchematic::new(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Box<dyn Error>> {
+        Ok(input.sum_of_gear_ratios().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* Test case:
+        467..114..
+        ...*......
+        ..35..633.
+        ......#...
+        617*......
+        .....+.58.
+        ..592.....
+        ......755.
+        ...$.*....
+        .664.598..
+    */
+    #[test]
+    fn test_game_points() {
+        let input = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+
+        // Create an instance of EngineSchematic
+        let schematic = EngineSchematic::new(input).unwrap();
+
+        // Call the sum_of_part_numbers method
+        let score = schematic.sum_of_gear_ratios();
+
+        let expected = 467835; // 467*35 + 598*755
+        assert_eq!(score, expected);
+
+        // there is no lenient grid, an empty or ragged line is always an error
+        assert!(EngineSchematic::new("467..\n\n..35.").is_err());
+        assert!(EngineSchematic::new("467..\n..35").is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+use std::error::Error;
+
+use aoc2023::parse::{each_line_in, integer, integers, is_strict, labelled, ParseError, Span};
+use aoc2023::Solver;
+
+// winning numbers and the player's numbers
+pub type Card = (HashSet<usize>, Vec<usize>);
+
+// parse a card into a tuple vector
+// Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+fn parse_card(card: Span) -> Result<Card, ParseError> {
+    let (head, numbers) = card.split_once(":")?;
+    integer::<usize>(labelled(head, "Card")?)?;
+    let (winning, player) = numbers.split_once("|")?;
+    let winning_numbers = integers(winning)?.into_iter().collect::<HashSet<usize>>();
+    let player_numbers = integers(player)?;
+
+    Ok((winning_numbers, player_numbers))
+}
+
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    parse_cards_in(input, is_strict())
+}
+
+pub fn parse_cards_in(input: &str, strict: bool) -> Result<Vec<Card>, ParseError> {
+    each_line_in(Span::new(input), strict, parse_card)
+}
+
+// add points only after each card
+fn calculate_card_points(winning_numbers: &HashSet<usize>, player_numbers: &[usize]) -> u32 {
+    let mut points = 0;
+    let mut matches = 0;
+
+    for &number in player_numbers {
+        if winning_numbers.contains(&number) {
+            matches += 1;
+            // Only add points if there are matches
+            if matches > 0 {
+                points = 2u32.pow(matches - 1);
+            }
+        }
+    }
+    points
+}
+
+pub fn total_points(cards: &[Card]) -> u32 {
+    cards
+        .iter()
+        .map(|(winning_numbers, player_numbers)| calculate_card_points(winning_numbers, player_numbers))
+        .sum()
+}
+
+pub fn calculate_score(input: &str) -> Result<u32, ParseError> {
+    Ok(total_points(&parse_cards(input)?))
+}
+
+pub struct Day04a;
+
+impl Solver for Day04a {
+    const DAY: u8 = 4;
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_cards(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, Box<dyn Error>> {
+        Ok(total_points(input).to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    /*
+    * Game: Calculate points for scratchcards.
+    * Structure: Each card has winning numbers and player's numbers.
+    * Points: points += 2u32.pow(matches - 1);
+    * Total: Add points from all cards for total score.
+    *
+    *   Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    *   Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+    *   Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+    *   Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+    *   Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+    *   Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+    *
+    */
+    #[test]
+    fn test_game_points() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\nCard 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19\nCard 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1\nCard 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83\nCard 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36\nCard 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let expected = 13; // 2*2*2 + 2 + 2 + 1 = 13
+        let score = calculate_score(input).unwrap();
+        assert_eq!(score, expected);
+    }
+}
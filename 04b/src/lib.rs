@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+use std::error::Error;
+
+use aoc2023::parse::{each_line_in, integer, integers, is_strict, labelled, ParseError, Span};
+use aoc2023::Solver;
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct Card {
+    id: usize,
+    winning_numbers: HashSet<usize>,
+    player_numbers: HashSet<usize>,
+    matching_numbers: Option<u32>,
+}
+
+impl Card {
+    fn new(id: usize, winning_numbers: HashSet<usize>, player_numbers: HashSet<usize>) -> Self {
+        Self {
+            id,
+            winning_numbers,
+            player_numbers,
+            matching_numbers: None,
+        }
+    }
+
+    fn calculate_matches(&mut self) -> u32 {
+        if let Some(matches) = self.matching_numbers {
+            return matches;
+        }
+
+        let matches = self.player_numbers
+            .intersection(&self.winning_numbers)
+            .count() as u32;
+
+        self.matching_numbers = Some(matches); // Cache the calculated value
+        matches
+    }
+
+
+
+    fn create_subsequent_copies(&self, cards: &[Card], matches: u32) -> Vec<Card> {
+        let mut new_cards = Vec::new();
+        for i in 1..=matches as usize {
+            if let Some(subsequent_card) = cards.get(self.id + i - 1) {
+                new_cards.push(subsequent_card.clone());
+            }
+        }
+        new_cards
+    }
+
+}
+
+// Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+fn parse_card(card: Span) -> Result<Card, ParseError> {
+    let (head, numbers) = card.split_once(":")?;
+    let card_id = integer(labelled(head, "Card")?)?;
+    let (player, winning) = numbers.split_once("|")?;
+
+    let player_numbers = integers(player)?.into_iter().collect::<HashSet<usize>>();
+    let winning_numbers = integers(winning)?.into_iter().collect::<HashSet<usize>>();
+
+    Ok(Card::new(card_id, winning_numbers, player_numbers))
+}
+
+
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    parse_cards_in(input, is_strict())
+}
+
+pub fn parse_cards_in(input: &str, strict: bool) -> Result<Vec<Card>, ParseError> {
+    each_line_in(Span::new(input), strict, parse_card)
+}
+
+
+pub fn play_cards(mut cards: Vec<Card>) -> Vec<Card> {
+    let mut i = 0;
+    while i < cards.len() {
+        // Clone the card to avoid borrow checker issues
+        let mut card = cards[i].clone();
+        let matches = card.calculate_matches();
+
+        if matches > 0 {
+            let new_cards = card.create_subsequent_copies(&cards, matches);
+            cards.extend(new_cards);
+        }
+
+        i += 1;
+    }
+    cards
+}
+
+
+pub struct Day04b;
+
+impl Solver for Day04b {
+    const DAY: u8 = 4;
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_cards(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, Box<dyn Error>> {
+        Ok(play_cards(input.clone()).len().to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    /*
+    *
+    * Card 1 has four matching numbers, so you win one copy each of the next four cards: cards 2, 3, 4, and 5.
+    * Your original card 2 has two matching numbers, so you win one copy each of cards 3 and 4.
+    * Your copy of card 2 also wins one copy each of cards 3 and 4.
+    * Your four instances of card 3 (one original and three copies) have two matching numbers, so you win four copies each of cards 4 and 5.
+    * Your eight instances of card 4 (one original and seven copies) have one matching number, so you win eight copies of card 5.
+    * Your fourteen instances of card 5 (one original and thirteen copies) have no matching numbers and win no more cards.
+    * Your one instance of card 6 (one original) has no matching numbers and wins no more cards.
+    * Once all of the originals and copies have been processed, you end up with 1 instance of card 1,
+    * 2 instances of card 2, 4 instances of card 3, 8 instances of card 4, 14 instances of card 5,
+    * and 1 instance of card 6. In total, this example pile of scratchcards causes you to ultimately have 30 scratchcards!
+    *
+    *   Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    *   Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+    *   Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+    *   Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+    *   Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+    *   Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+    *
+    */
+    #[test]
+    fn test_game_points() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\nCard 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19\nCard 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1\nCard 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83\nCard 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36\nCard 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let expected = 30; // 
+        let mut cards = parse_cards(input).unwrap();
+        cards = play_cards(cards);
+        let score = cards.len();
+        assert_eq!(score, expected);
+    }
+}
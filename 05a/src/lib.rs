@@ -0,0 +1,197 @@
+use std::error::Error;
+
+use aoc2023::parse::{blocks, each_line_in, integers, is_strict, section, ParseError, Span};
+use aoc2023::Solver;
+
+#[derive(Debug)]
+pub enum Garden {
+    Seed(Seed),
+    Soil(GardenMapping),
+    Fertilizer(GardenMapping),
+    Water(GardenMapping),
+    Light(GardenMapping),
+    Temperature(GardenMapping),
+    Humidity(GardenMapping),
+    Location(GardenMapping),
+    Unknown,
+}
+
+#[derive(Debug)]
+pub struct Seed {
+    pub id: u64,
+}
+
+#[derive(Debug)]
+pub struct GardenMapping {
+    pub dst: u64,
+    pub src: u64,
+    pub range: u64,
+}
+
+impl GardenMapping {
+    fn new(dst: u64, src: u64, range: u64) -> Self {
+        GardenMapping { dst, src, range }
+    }
+}
+
+
+pub fn parse_data(input: &str) -> Result<Vec<Garden>, ParseError> {
+    parse_data_in(input, is_strict())
+}
+
+// Strict, a map has to have a known title and lines of exactly three numbers.
+pub fn parse_data_in(input: &str, strict: bool) -> Result<Vec<Garden>, ParseError> {
+    let mut garden_data = Vec::new();
+
+    // Sections are separated by blank lines, the seeds come first
+    let mut sections = blocks(Span::new(input));
+    let first = sections.next().ok_or_else(|| Span::new(input).error("a `seeds:` section"))?;
+    let (title, seeds) = section(first)?;
+    if title.as_str() != "seeds" {
+        return Err(title.error("`seeds`"));
+    }
+    garden_data.extend(integers(seeds)?.into_iter().map(|id| Garden::Seed(Seed { id })));
+
+    for block in sections {
+        let (title, data) = section(block)?;
+        let mappings = each_line_in(data, strict, |line| parse_garden_mapping(line, strict))?;
+        if strict && mappings.is_empty() {
+            return Err(data.error("three integers"));
+        }
+        for mapping in mappings {
+            let garden = map_title_to_garden(&title, mapping);
+            if strict && matches!(garden, Garden::Unknown) {
+                return Err(title.error("the title of a known map, e.g. `seed-to-soil map`"));
+            }
+            garden_data.push(garden);
+        }
+    }
+
+    Ok(garden_data)
+}
+
+
+fn map_title_to_garden(title: &str, mapping: GardenMapping) -> Garden {
+    match title {
+        "seed-to-soil map" => Garden::Soil(mapping),
+        "soil-to-fertilizer map" => Garden::Fertilizer(mapping),
+        "fertilizer-to-water map" => Garden::Water(mapping),
+        "water-to-light map" => Garden::Light(mapping),
+        "light-to-temperature map" => Garden::Temperature(mapping),
+        "temperature-to-humidity map" => Garden::Humidity(mapping),
+        "humidity-to-location map" => Garden::Location(mapping),
+        _ => Garden::Unknown, // Or handle this case as you see fit
+    }
+}
+
+
+fn parse_garden_mapping(line: Span, strict: bool) -> Result<GardenMapping, ParseError> {
+    match integers::<u64>(line)?[..] {
+        [dst, src, range] => Ok(GardenMapping::new(dst, src, range)),
+        // anything after the three numbers is only ignored when lenient
+        [dst, src, range, ..] if !strict => Ok(GardenMapping::new(dst, src, range)),
+        _ => Err(line.trim().error("three integers")),
+    }
+}
+
+
+pub struct Day05a;
+
+impl Solver for Day05a {
+    const DAY: u8 = 5;
+    const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+    type Input = Vec<Garden>;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_data(input)?)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn test_game_points() {
+        let data = parse_data(EXAMPLE).unwrap();
+        let seeds: Vec<u64> = data
+            .iter()
+            .filter_map(|garden| match garden {
+                Garden::Seed(seed) => Some(seed.id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(seeds, [79, 14, 55, 13]);
+        // mappings per map, seed-to-soil through humidity-to-location, so every
+        // title went to its own map
+        let mut maps = [0; 7];
+        for garden in &data {
+            match garden {
+                Garden::Soil(_) => maps[0] += 1,
+                Garden::Fertilizer(_) => maps[1] += 1,
+                Garden::Water(_) => maps[2] += 1,
+                Garden::Light(_) => maps[3] += 1,
+                Garden::Temperature(_) => maps[4] += 1,
+                Garden::Humidity(_) => maps[5] += 1,
+                Garden::Location(_) => maps[6] += 1,
+                Garden::Seed(_) | Garden::Unknown => {}
+            }
+        }
+        assert!(!data.iter().any(|garden| matches!(garden, Garden::Unknown)));
+        assert_eq!(maps, [2, 3, 4, 2, 3, 2, 2]);
+        assert!(matches!(data[4], Garden::Soil(GardenMapping { dst: 50, src: 98, range: 2 })));
+        assert!(matches!(data[21], Garden::Location(GardenMapping { dst: 56, src: 93, range: 4 })));
+    }
+
+    #[test]
+    fn test_strict_and_lenient() {
+        let empty_map = "seeds: 79\n\nseed-to-soil map:\n";
+        assert_eq!(parse_data_in(empty_map, true).unwrap_err().line, 3);
+        assert_eq!(parse_data_in(empty_map, false).unwrap().len(), 1);
+
+        let unknown = "seeds: 79\n\nseed-to-dirt map:\n50 98 2\n";
+        let err = parse_data_in(unknown, true).unwrap_err();
+        assert_eq!((err.line, err.found.as_str()), (3, "seed-to-dirt map"));
+        assert!(matches!(parse_data_in(unknown, false).unwrap()[..], [Garden::Seed(_), Garden::Unknown]));
+
+        let extra = "seeds: 79\n\nseed-to-soil map:\n50 98 2 7\n";
+        assert_eq!(parse_data_in(extra, true).unwrap_err().line, 4);
+        let data = parse_data_in(extra, false).unwrap();
+        assert!(matches!(data[1], Garden::Soil(GardenMapping { dst: 50, src: 98, range: 2 })));
+    }
+}
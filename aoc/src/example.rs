@@ -0,0 +1,101 @@
+use std::error::Error;
+use std::path::PathBuf;
+
+use aoc2023::example::{self, Example};
+use aoc2023::{Part, Profile, Registry};
+
+// Crate directory and solver type answering a day's part, e.g. ("04b", "Day04b").
+// Parts without a registered solver get the names a new crate for them would have.
+pub fn solver_crate(registry: &Registry, day: u8, part: Part) -> (String, String) {
+    let registered = registry
+        .get(day, part)
+        .and_then(|entry| entry.name.strip_prefix("aoc")?.split_once("::"));
+    match registered {
+        Some((krate, ty)) => (krate.to_string(), ty.to_string()),
+        None => {
+            let krate = format!("{:02}{}", day, if part == Part::One { 'a' } else { 'b' });
+            let ty = format!("Day{}", krate);
+            (krate, ty)
+        }
+    }
+}
+
+// Test for the crate's `mod tests`, with the example inline since the copy next
+// to the input lives outside the crate.
+pub fn test_snippet(ty: &str, example: &Example) -> String {
+    let expected = match &example.answer {
+        Some(answer) => format!("\"{}\"", answer),
+        None => "\"?\" /* no answer found in the description */".to_string(),
+    };
+    format!(
+        "    #[test]
+    fn test_example_part{part}() {{
+        let parsed = {ty}::parse({input:?}).unwrap();
+        assert_eq!({ty}::part{part}(&parsed).unwrap(), {expected});
+    }}
+",
+        part = example.part,
+        ty = ty,
+        input = example.input,
+        expected = expected
+    )
+}
+
+// The description and its examples are cached next to the day's input, per
+// profile since part two only shows for an account that solved part one.
+pub fn page_path(profile: &Profile, day: u8) -> PathBuf {
+    profile.input_path(aoc2023::YEAR, day).with_file_name("puzzle.html")
+}
+
+pub fn example_path(profile: &Profile, day: u8, part: Part) -> PathBuf {
+    profile.input_path(aoc2023::YEAR, day).with_file_name(format!("example{}.txt", part))
+}
+
+pub async fn example(registry: &Registry, profile: &Profile, day: u8, refresh: bool) -> Result<(), Box<dyn Error>> {
+    let page_path = page_path(profile, day);
+
+    // The description is public, logging in only adds part two once part one is solved.
+    let page = example::get_puzzle_page(day, profile.cookie(), &page_path.to_string_lossy(), refresh).await?;
+
+    let examples = example::extract_examples(&page);
+    if examples.is_empty() {
+        return Err(format!("no examples found on {}", example::puzzle_url(day)).into());
+    }
+
+    for ex in &examples {
+        let (_, ty) = solver_crate(registry, day, ex.part);
+        let path = example_path(profile, day, ex.part);
+        aoc2023::save_to_file(&path.to_string_lossy(), &ex.input)?;
+
+        let status = match (registry.get(day, ex.part), &ex.answer) {
+            (Some(entry), Some(expected)) => match entry.run(&ex.input) {
+                Ok(answer) if &answer == expected => "passes".to_string(),
+                Ok(answer) => format!("fails, got {}", answer),
+                Err(e) => format!("fails: {}", e),
+            },
+            _ => "not checked".to_string(),
+        };
+        println!(
+            "day {} part {}: wrote {}, expected answer {} ({})",
+            day,
+            ex.part,
+            path.display(),
+            ex.answer.as_deref().unwrap_or("unknown"),
+            status
+        );
+        println!("{}", test_snippet(&ty, ex));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solver_crate() {
+        let registry = crate::registry::registry();
+        assert_eq!(solver_crate(&registry, 4, Part::Two), ("04b".to_string(), "Day04b".to_string()));
+        assert_eq!(solver_crate(&registry, 9, Part::One), ("09a".to_string(), "Day09a".to_string()));
+    }
+}
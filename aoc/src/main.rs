@@ -0,0 +1,492 @@
+use std::env;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc2023::bench::{self, Budget, History, Phase};
+use aoc2023::solver::Unsolved;
+use aoc2023::unlock;
+use aoc2023::config::Config;
+use aoc2023::{AocError, Ledger, Part, Profile, Registry};
+use clap::{Parser, Subcommand};
+
+mod example;
+mod registry;
+mod scaffold;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 runner")]
+struct Cli {
+    /// Account to use, as named in aoc.toml (defaults to AOC_PROFILE or default_profile)
+    #[arg(long, global = true)]
+    profile: Option<String>,
+    /// Never touch the network, fail when something isn't cached
+    #[arg(long, global = true)]
+    offline: bool,
+    /// Reject inputs with empty lines, trailing garbage or unknown sections (the default)
+    #[arg(long, global = true)]
+    strict: bool,
+    /// Skip what doesn't parse instead of failing, for experimenting with inputs (`--strict` wins)
+    #[arg(long, global = true)]
+    lenient: bool,
+    /// Log more, once for info, twice for debug, three times for trace (AOC_LOG overrides)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, a range of days (e.g. 1-3) or all registered days
+    Run {
+        #[arg(default_value = "all")]
+        days: Days,
+        /// Only solve the given part (1 or 2)
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Submit each answer to adventofcode.com
+        #[arg(long)]
+        submit: bool,
+        /// Read the input from this file, or stdin for `-`, instead of the cache (single day only)
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Time parsing and solving separately and compare with the previous run
+    Bench {
+        #[arg(default_value = "all")]
+        days: Days,
+        /// Only benchmark the given part (1 or 2)
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Measured iterations per phase
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+        /// Untimed iterations before measuring
+        #[arg(long, default_value_t = 10)]
+        warmup: usize,
+        /// Stop measuring a phase after this many seconds
+        #[arg(long, default_value_t = 5.0)]
+        max_time: f64,
+        /// Read the input from this file, or stdin for `-`, instead of the cache (single day only)
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Sleep until a day unlocks, then download its input and print some stats about it
+    WaitAndFetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Save the examples of a day's description next to its input and print tests for them
+    Example {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Fetch the description again, e.g. to pick up part two
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Create the crate for a day's next part, with its input and a failing test from the example
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part the crate answers (defaults to 1, or 2 once part 1 has a solver)
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+    },
+    /// Show a private leaderboard, ranked, with how long part two took each day
+    Leaderboard {
+        /// Leaderboard id, the number in its URL (defaults to `leaderboard` in aoc.toml)
+        id: Option<u64>,
+    },
+    /// Manage the adventofcode.com session
+    Auth {
+        #[command(subcommand)]
+        command: AuthCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum AuthCommand {
+    /// Tell who the session cookie of the profile is logged in as, or that it expired
+    Check,
+    /// Look for anything resembling a session token in the files tracked by git
+    Scrub,
+}
+
+// Inclusive range of puzzle days picked on the command line.
+#[derive(Clone, Copy, Debug)]
+struct Days {
+    first: u8,
+    last: u8,
+}
+
+impl Days {
+    fn contains(&self, day: u8) -> bool {
+        (self.first..=self.last).contains(&day)
+    }
+
+    // An input given on the command line belongs to exactly one day.
+    fn check_input(&self, input: &Option<String>) -> Result<(), String> {
+        match input {
+            Some(_) if self.first != self.last => Err("--input needs a single day, e.g. `run 4 --input -`".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |d: &str| match d.trim().parse::<u8>() {
+            Ok(day @ 1..=25) => Ok(day),
+            _ => Err(format!("invalid day `{}`, expected 1-25", d)),
+        };
+
+        let days = match s.split_once('-') {
+            _ if s == "all" => Days { first: 1, last: 25 },
+            Some((first, last)) => Days { first: day(first)?, last: day(last)? },
+            None => {
+                let d = day(s)?;
+                Days { first: d, last: d }
+            }
+        };
+        if days.first > days.last {
+            return Err(format!("empty day range `{}`", s));
+        }
+        Ok(days)
+    }
+}
+
+fn parse_part(s: &str) -> Result<Part, String> {
+    let part = s.parse::<u8>().map_err(|e| e.to_string())?;
+    Part::try_from(part)
+}
+
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+// Transient failures (rate limiting, timeouts, 5xx) are retried this many times in total.
+const FETCH_ATTEMPTS: u32 = 3;
+
+async fn load_input(profile: &Profile, day: u8) -> Result<String, AocError> {
+    let mut attempt = 1;
+    loop {
+        match aoc2023::get_data(aoc2023::YEAR, day, profile).await {
+            Err(e) if e.is_retryable() && attempt < FETCH_ATTEMPTS => {
+                let wait = match e {
+                    AocError::RateLimited { retry_after: Some(wait) } => wait,
+                    _ => Duration::from_secs(2u64.pow(attempt)),
+                };
+                eprintln!("day {}: {}, retrying in {}s", day, e, wait.as_secs());
+                tokio::time::sleep(wait).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+// Input of one day: the `--input` file (or stdin) when one is given, the cache otherwise.
+async fn day_input(profile: &Profile, day: u8, source: Option<&str>) -> Result<String, AocError> {
+    match source {
+        Some(source) => aoc2023::read_input(source),
+        None => load_input(profile, day).await,
+    }
+}
+
+async fn run(
+    registry: &Registry,
+    profile: &Profile,
+    days: Days,
+    part: Option<Part>,
+    submit: bool,
+    source: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    days.check_input(&source)?;
+    let mut ledger = Ledger::load(profile.ledger_path())?;
+    let mut failed = 0;
+
+    for day in registry.days().into_iter().filter(|&d| days.contains(d)) {
+        let input = match day_input(profile, day, source.as_deref()).await {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("day {}: failed to load input: {}", day, e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        for p in Part::ALL.into_iter().filter(|&p| part.is_none_or(|want| want == p)) {
+            let Some(entry) = registry.get(day, p) else {
+                println!("day {} part {}: no solver registered", day, p);
+                continue;
+            };
+            match aoc2023::report::run(entry, profile, &input) {
+                Ok(report) => {
+                    println!("day {} part {}: {}", day, p, report.answer);
+                    if let Err(e) = report.save(aoc2023::report::default_dir()) {
+                        eprintln!("day {} part {}: {}", day, p, e);
+                        failed += 1;
+                    }
+                    // The ledger only knows the answers for the profile's own input.
+                    if source.is_some() {
+                        continue;
+                    }
+                    if let Err(e) = aoc2023::submit::check_answer(&mut ledger, profile, day, p, &report.answer, submit).await {
+                        eprintln!("day {} part {}: {}", day, p, e);
+                        failed += 1;
+                    }
+                }
+                Err(e) if e.is::<Unsolved>() => println!("{}", e),
+                Err(e) => {
+                    eprintln!("day {} part {}: {}", day, p, e);
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} solver(s) failed", failed).into());
+    }
+    Ok(())
+}
+
+async fn bench(
+    registry: &Registry,
+    profile: &Profile,
+    days: Days,
+    part: Option<Part>,
+    budget: Budget,
+    source: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    days.check_input(&source)?;
+    let mut history = History::load(aoc2023::bench::default_path())?;
+    println!(
+        "{:>3} {:>4} {:<5} {:>6} {:>10} {:>10} {:>10} {:>8}",
+        "day", "part", "phase", "iters", "mean", "median", "stddev", "vs prev"
+    );
+
+    for day in registry.days().into_iter().filter(|&d| days.contains(d)) {
+        let input = match day_input(profile, day, source.as_deref()).await {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("day {}: failed to load input: {}", day, e);
+                continue;
+            }
+        };
+
+        for p in Part::ALL.into_iter().filter(|&p| part.is_none_or(|want| want == p)) {
+            let Some(entry) = registry.get(day, p) else { continue };
+
+            // Only time solvers that actually produce an answer.
+            let parsed = match entry.parse(&input).and_then(|parsed| entry.solve(parsed.as_ref()).map(|_| parsed)) {
+                Ok(parsed) => parsed,
+                Err(e) if e.is::<Unsolved>() => continue,
+                Err(e) => {
+                    eprintln!("day {} part {}: {}", day, p, e);
+                    continue;
+                }
+            };
+
+            let parse_stats = bench::measure(budget, || entry.parse(&input));
+            let solve_stats = bench::measure(budget, || entry.solve(parsed.as_ref()));
+
+            for (phase, stats) in [(Phase::Parse, parse_stats), (Phase::Solve, solve_stats)] {
+                let change = match history.previous(day, p, phase) {
+                    Some(prev) => format!("{:+.1}%", (stats.mean.as_secs_f64() / prev.stats.mean.as_secs_f64() - 1.0) * 100.0),
+                    None => "-".to_string(),
+                };
+                println!(
+                    "{:>3} {:>4} {:<5} {:>6} {:>10} {:>10} {:>10} {:>8}",
+                    day,
+                    p,
+                    phase,
+                    stats.iterations,
+                    bench::format_duration(stats.mean),
+                    bench::format_duration(stats.median),
+                    bench::format_duration(stats.stddev),
+                    change
+                );
+                history.save(day, p, phase, stats)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_input_stats(input: &str) {
+    let lines: Vec<&str> = input.lines().collect();
+    let widths: Vec<usize> = lines.iter().map(|l| l.chars().count()).collect();
+    let numbers = input
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|s| !s.is_empty())
+        .count();
+
+    println!("bytes:       {}", input.len());
+    println!("lines:       {} ({} blank)", lines.len(), lines.iter().filter(|l| l.trim().is_empty()).count());
+    println!(
+        "line length: {}..={}",
+        widths.iter().min().unwrap_or(&0),
+        widths.iter().max().unwrap_or(&0)
+    );
+    if !widths.is_empty() && widths.iter().all(|&w| w == widths[0]) {
+        println!("grid:        {}x{}", widths[0], lines.len());
+    }
+    println!("numbers:     {}", numbers);
+    if let Some(first) = lines.first() {
+        println!("first line:  {}", first.chars().take(60).collect::<String>());
+    }
+}
+
+async fn wait_and_fetch(profile: &Profile, day: u8) -> Result<(), Box<dyn Error>> {
+    let unlock = unlock::unlock_time(aoc2023::YEAR, day).ok_or(AocError::NoSuchPuzzle { year: aoc2023::YEAR, day })?;
+
+    // A couple of seconds of jitter, so we aren't part of the stampede at the exact unlock second.
+    let jitter_ms = 2_000 + SystemTime::now().duration_since(UNIX_EPOCH)?.subsec_nanos() as u64 % 3_000;
+    let target = unlock + Duration::from_millis(jitter_ms);
+
+    let mut announced = false;
+    loop {
+        let remaining = target.duration_since(SystemTime::now()).unwrap_or(Duration::ZERO);
+        if remaining.is_zero() {
+            break;
+        }
+        if !announced {
+            println!("day {} unlocks in {}, waiting...", day, unlock::format_remaining(remaining));
+            announced = true;
+        }
+        // Sleep in short steps, so a suspended laptop doesn't oversleep the unlock.
+        tokio::time::sleep(remaining.min(Duration::from_secs(30))).await;
+    }
+
+    let input = load_input(profile, day).await?;
+    println!("day {} input saved to {}", day, profile.input_path(aoc2023::YEAR, day).display());
+    print_input_stats(&input);
+    Ok(())
+}
+
+async fn leaderboard(profile: &Profile, id: Option<u64>) -> Result<(), Box<dyn Error>> {
+    let id = id.ok_or("no leaderboard id given, and no `leaderboard` in aoc.toml")?;
+    let board = aoc2023::leaderboard::get_leaderboard(profile, id).await?;
+    print!("{}", board.render());
+    Ok(())
+}
+
+async fn auth_check(profile: &Profile) -> Result<(), Box<dyn Error>> {
+    let user = aoc2023::auth::check_session(profile).await?;
+    match user.stars {
+        Some(stars) => println!("profile {}: logged in as {} ({}*)", profile.name, user.name, stars),
+        None => println!("profile {}: logged in as {}", profile.name, user.name),
+    }
+    Ok(())
+}
+
+fn auth_scrub() -> Result<(), Box<dyn Error>> {
+    let root = workspace_root();
+    let output = std::process::Command::new("git").arg("-C").arg(&root).args(["ls-files", "-z"]).output()?;
+    if !output.status.success() {
+        return Err(format!("git ls-files failed: {}", String::from_utf8_lossy(&output.stderr).trim()).into());
+    }
+
+    let mut found = 0;
+    for file in String::from_utf8(output.stdout)?.split('\0').filter(|f| !f.is_empty()) {
+        // Binary and unreadable files can't hold a pasted cookie.
+        let Ok(text) = std::fs::read_to_string(root.join(file)) else { continue };
+        for (line, col) in aoc2023::session::find_session_tokens(&text) {
+            println!("{}:{}:{}: looks like a session token", file, line, col);
+            found += 1;
+        }
+    }
+
+    if found > 0 {
+        return Err(format!("{} possible session token(s) in tracked files, remove them and log out of adventofcode.com to revoke them", found).into());
+    }
+    println!("no session tokens in tracked files");
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    dotenv::dotenv().ok(); // Load .env file
+    let cli = Cli::parse();
+    aoc2023::client::set_offline(cli.offline);
+    aoc2023::parse::set_strict(aoc2023::parse::strict_mode(cli.strict, cli.lenient));
+    aoc2023::logging::init(cli.verbose);
+    let registry = registry::registry();
+    // Only loaded for the commands that need it, so a broken aoc.toml doesn't get
+    // in the way of `auth scrub` or `new`.
+    let select = || {
+        Config::load(aoc2023::config::default_path())
+            .and_then(|config| Ok((Profile::select(&config, cli.profile.as_deref())?, config)))
+    };
+
+    let result = match cli.command {
+        Command::Auth { command: AuthCommand::Scrub } => auth_scrub(),
+        Command::New { day, part } => match select() {
+            Ok((profile, _)) => scaffold::new(&registry, Some(&profile), day, part).await,
+            Err(e) => {
+                println!("no profile, so no example or input: {}", e);
+                scaffold::new(&registry, None, day, part).await
+            }
+        },
+        command => match select() {
+            Ok((profile, config)) => dispatch(command, &registry, &profile, &config).await,
+            Err(e) => Err(e.into()),
+        },
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+// The commands that need a profile.
+async fn dispatch(command: Command, registry: &Registry, profile: &Profile, config: &Config) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Run { days, part, submit, input } => run(registry, profile, days, part, submit, input).await,
+        Command::Bench { days, part, iterations, warmup, max_time, input } => {
+            let budget = Budget { warmup, iterations, max_time: Duration::from_secs_f64(max_time) };
+            bench(registry, profile, days, part, budget, input).await
+        }
+        Command::WaitAndFetch { day } => wait_and_fetch(profile, day).await,
+        Command::Example { day, refresh } => example::example(registry, profile, day, refresh).await,
+        Command::Leaderboard { id } => leaderboard(profile, id.or(config.leaderboard)).await,
+        Command::Auth { command: AuthCommand::Check } => auth_check(profile).await,
+        Command::New { .. } | Command::Auth { command: AuthCommand::Scrub } => unreachable!("run without a profile"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        let single: Days = "4".parse().unwrap();
+        assert!(single.contains(4) && !single.contains(5));
+
+        let range: Days = "1-3".parse().unwrap();
+        assert!(range.contains(1) && range.contains(3) && !range.contains(4));
+
+        let all: Days = "all".parse().unwrap();
+        assert!(all.contains(1) && all.contains(25));
+
+        assert!("0".parse::<Days>().is_err());
+        assert!("3-1".parse::<Days>().is_err());
+        assert!("26".parse::<Days>().is_err());
+    }
+
+    #[test]
+    fn test_registry_covers_days() {
+        let registry = registry::registry();
+        assert_eq!(registry.days(), vec![1, 2, 3, 4, 5]);
+        assert!(registry.get(4, Part::Two).is_some());
+    }
+}
@@ -0,0 +1,17 @@
+use aoc2023::{Part, Registry};
+
+// Every solved day and the part each crate answers.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register::<aoc01a::Day01a>(Part::One)
+        .register::<aoc01b::Day01b>(Part::Two)
+        .register::<aoc02a::Day02a>(Part::One)
+        .register::<aoc02b::Day02b>(Part::Two)
+        .register::<aoc03a::Day03a>(Part::One)
+        .register::<aoc03b::Day03b>(Part::Two)
+        .register::<aoc04a::Day04a>(Part::One)
+        .register::<aoc04b::Day04b>(Part::Two)
+        .register::<aoc05a::Day05a>(Part::One);
+    registry
+}
@@ -0,0 +1,242 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use aoc2023::example::{self, Example};
+use aoc2023::{AocError, Part, Profile, Registry};
+
+use crate::example::{solver_crate, test_snippet};
+
+// Part a new crate for `day` answers: one unless part one already has a solver.
+pub fn next_part(registry: &Registry, day: u8) -> Part {
+    match registry.get(day, Part::One) {
+        Some(_) => Part::Two,
+        None => Part::One,
+    }
+}
+
+fn cargo_toml(krate: &str) -> String {
+    format!(
+        "[package]
+name = \"aoc{krate}\"
+version.workspace = true
+edition.workspace = true
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc2023.workspace = true
+dotenv.workspace = true
+tokio.workspace = true
+"
+    )
+}
+
+fn main_rs(krate: &str, ty: &str, day: u8, part: Part) -> String {
+    format!(
+        "use std::error::Error;
+use std::process::ExitCode;
+
+use aoc{krate}::{ty};
+use aoc2023::Part;
+
+async fn run() -> Result<(), Box<dyn Error>> {{
+    let profile = aoc2023::Profile::from_args()?;
+    let body = aoc2023::get_input(aoc2023::YEAR, {day}, &profile).await?;
+
+    let report = aoc2023::report::solve::<{ty}>(Part::{part:?}, &profile, &body)?;
+    println!(\"{{}}\", report.answer);
+    aoc2023::submit::check_and_submit(&profile, {day}, Part::{part:?}, &report.answer).await?;
+
+    Ok(())
+}}
+
+#[tokio::main]
+async fn main() -> ExitCode {{
+    dotenv::dotenv().ok(); // Load .env file
+    aoc2023::logging::init_from_args();
+    aoc2023::parse::init_from_args();
+    if let Err(e) = run().await {{
+        eprintln!(\"error: {{}}\", e);
+        return ExitCode::FAILURE;
+    }}
+    ExitCode::SUCCESS
+}}
+"
+    )
+}
+
+// The solver starts out answering nothing, so the example test fails until it does.
+fn lib_rs(ty: &str, day: u8, example: Option<&Example>) -> String {
+    let tests = match example {
+        Some(example) => format!("\n#[cfg(test)]\nmod tests {{\n    use super::*;\n\n{}}}\n", test_snippet(ty, example)),
+        None => String::new(),
+    };
+    format!(
+        "use std::error::Error;
+
+use aoc2023::Solver;
+
+pub struct {ty};
+
+impl Solver for {ty} {{
+    const DAY: u8 = {day};
+    const VERSION: &'static str = env!(\"CARGO_PKG_VERSION\");
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+}}
+{tests}"
+    )
+}
+
+// Adds `line` to the run of lines `in_block` matches, keeping the run sorted.
+fn insert_sorted(text: &str, line: &str, in_block: impl Fn(&str) -> bool) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let start = lines.iter().position(|l| in_block(l)).ok_or_else(|| format!("nowhere to add `{}`", line.trim()))?;
+    let end = start + lines[start..].iter().take_while(|l| in_block(l)).count();
+    if lines[start..end].contains(&line) {
+        return Err(format!("`{}` is already there", line.trim()));
+    }
+    let at = start + lines[start..end].iter().take_while(|&&l| l < line).count();
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn is_day_crate(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    bytes.len() == 3 && bytes[0].is_ascii_digit() && bytes[1].is_ascii_digit() && bytes[2].is_ascii_lowercase()
+}
+
+// Workspace member and dependency, e.g. `"06a",` and `aoc06a = { path = "06a" }`.
+fn add_to_workspace(text: &str, krate: &str) -> Result<String, String> {
+    let member = |l: &str| l.trim().strip_prefix('"').and_then(|l| l.strip_suffix("\",")).is_some_and(is_day_crate);
+    let text = insert_sorted(text, &format!("    \"{}\",", krate), member)?;
+    let dependency = |l: &str| l.strip_prefix("aoc").and_then(|l| l.get(..3)).is_some_and(is_day_crate);
+    insert_sorted(&text, &format!("aoc{0} = {{ path = \"{0}\" }}", krate), dependency)
+}
+
+fn add_to_runner(text: &str, krate: &str) -> Result<String, String> {
+    let dependency = |l: &str| l.strip_prefix("aoc").and_then(|l| l.get(..3)).is_some_and(is_day_crate);
+    insert_sorted(text, &format!("aoc{}.workspace = true", krate), dependency)
+}
+
+// The chain of `.register` calls ends with the `;`, which has to stay on its last line.
+fn add_to_registry(text: &str, krate: &str, ty: &str, part: Part) -> Result<String, String> {
+    let register = |l: &str| l.trim_start().starts_with(".register::<");
+    let unterminated: String = text
+        .lines()
+        .map(|l| if register(l) { l.trim_end_matches(';') } else { l })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let line = format!("        .register::<aoc{}::{}>(Part::{:?})", krate, ty, part);
+    let text = insert_sorted(&unterminated, &line, register)?;
+
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+    let last = lines.iter().rposition(|l| register(l)).expect("just inserted");
+    lines[last].push(';');
+    Ok(lines.join("\n") + "\n")
+}
+
+fn edit(path: &Path, change: impl FnOnce(&str) -> Result<String, String>) -> Result<(), Box<dyn Error>> {
+    let text = fs::read_to_string(path).map_err(|e| AocError::io(path, e))?;
+    let changed = change(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+    aoc2023::save_to_file(&path.to_string_lossy(), &changed)?;
+    Ok(())
+}
+
+// The example for `part`, from the description of a day that's unlocked.
+async fn fetch_example(profile: &Profile, day: u8, part: Part) -> Result<Option<Example>, AocError> {
+    let page_path = crate::example::page_path(profile, day);
+    let page = example::get_puzzle_page(day, profile.cookie(), &page_path.to_string_lossy(), part == Part::Two).await?;
+    Ok(example::extract_examples(&page).into_iter().find(|ex| ex.part == part))
+}
+
+// Creates the crate for `day`'s `part`, e.g. 06a, registers it with the workspace
+// and the runner, and fills in its input and a test from the example when the
+// puzzle is unlocked already and there is a profile to fetch them as.
+pub async fn new(registry: &Registry, profile: Option<&Profile>, day: u8, part: Option<Part>) -> Result<(), Box<dyn Error>> {
+    let part = part.unwrap_or_else(|| next_part(registry, day));
+    let (krate, ty) = solver_crate(registry, day, part);
+    let root = crate::workspace_root();
+    let dir = root.join(&krate);
+    // Only the legacy input cache, 06a/output, may be there already.
+    if registry.get(day, part).is_some() || dir.join("Cargo.toml").exists() {
+        return Err(format!("day {} part {} already has a crate, {}", day, part, dir.display()).into());
+    }
+
+    let example = match profile {
+        Some(profile) => match fetch_example(profile, day, part).await {
+            Ok(example) => example,
+            Err(e) => {
+                println!("no example yet: {}", e);
+                None
+            }
+        },
+        None => None,
+    };
+    if let (Some(profile), Some(example)) = (profile, &example) {
+        let path = crate::example::example_path(profile, day, part);
+        aoc2023::save_to_file(&path.to_string_lossy(), &example.input)?;
+    }
+
+    aoc2023::save_to_file(&dir.join("Cargo.toml").to_string_lossy(), &cargo_toml(&krate))?;
+    aoc2023::save_to_file(&dir.join("src/main.rs").to_string_lossy(), &main_rs(&krate, &ty, day, part))?;
+    aoc2023::save_to_file(&dir.join("src/lib.rs").to_string_lossy(), &lib_rs(&ty, day, example.as_ref()))?;
+    edit(&root.join("Cargo.toml"), |text| add_to_workspace(text, &krate))?;
+    edit(&root.join("aoc/Cargo.toml"), |text| add_to_runner(text, &krate))?;
+    edit(&root.join("aoc/src/registry.rs"), |text| add_to_registry(text, &krate, &ty, part))?;
+    println!("created {} with {}", dir.display(), ty);
+
+    if let Some(profile) = profile {
+        match aoc2023::get_data(aoc2023::YEAR, day, profile).await {
+            Ok(_) => println!("day {} input saved to {}", day, profile.input_path(aoc2023::YEAR, day).display()),
+            Err(e) => println!("no input yet: {}", e),
+        }
+    }
+    match &example {
+        Some(_) => println!("`cargo test -p aoc{}` fails until part {} is solved", krate, part),
+        None => println!("run `aoc example {}` once it unlocks for a test", day),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_part() {
+        let registry = crate::registry::registry();
+        assert_eq!(next_part(&registry, 5), Part::Two);
+        assert_eq!(next_part(&registry, 6), Part::One);
+    }
+
+    #[test]
+    fn test_wire_up() {
+        let workspace = "[workspace]\nmembers = [\n    \"lib\",\n    \"05a\",\n    \"07a\",\n]\n\n[workspace.dependencies]\naoc2023 = { path = \"lib\" }\naoc05a = { path = \"05a\" }\nclap = \"4\"\n";
+        let added = add_to_workspace(workspace, "06a").unwrap();
+        assert!(added.contains("    \"05a\",\n    \"06a\",\n    \"07a\",\n"));
+        assert!(added.contains("aoc05a = { path = \"05a\" }\naoc06a = { path = \"06a\" }\nclap"));
+        assert!(add_to_workspace(&added, "06a").is_err());
+
+        let registry = "    registry\n        .register::<aoc05a::Day05a>(Part::One);\n    registry\n";
+        let added = add_to_registry(registry, "05b", "Day05b", Part::Two).unwrap();
+        assert_eq!(
+            added,
+            "    registry\n        .register::<aoc05a::Day05a>(Part::One)\n        .register::<aoc05b::Day05b>(Part::Two);\n    registry\n"
+        );
+    }
+
+    #[test]
+    fn test_lib_rs_with_example() {
+        let example = Example { part: Part::One, input: "1\n".to_string(), answer: Some("35".to_string()) };
+        let lib = lib_rs("Day05a", 5, Some(&example));
+        assert!(lib.contains("const DAY: u8 = 5;"));
+        assert!(lib.contains("let parsed = Day05a::parse(\"1\\n\").unwrap();"));
+        assert!(lib.contains("assert_eq!(Day05a::part1(&parsed).unwrap(), \"35\");"));
+        assert!(!lib_rs("Day05a", 5, None).contains("mod tests"));
+    }
+}
@@ -0,0 +1,64 @@
+use crate::client::Client;
+use crate::error::{self, AocError};
+use crate::html;
+use crate::profile::Profile;
+use crate::session::SessionCookie;
+
+/// The account a session cookie is logged in as.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct User {
+    /// Display name, or "(anonymous user #1234)" for accounts without one.
+    pub name: String,
+    /// Stars over all events, as shown in the page header.
+    pub stars: Option<u32>,
+}
+
+// Every page has a `<div class="user">name <span class="star-count">48*</span></div>`
+// in its header when logged in, and a login link instead when not.
+pub fn parse_user(html: &str) -> Option<User> {
+    let div = html::between(html, "<div class=\"user\">", "</div>").into_iter().next()?;
+    let name = html::decode_entities(div.split('<').next().unwrap_or("").trim());
+    if name.is_empty() {
+        return None;
+    }
+    let stars = html::between(div, "<span class=\"star-count\">", "</span>")
+        .first()
+        .and_then(|count| count.trim().trim_end_matches('*').parse().ok());
+    Some(User { name, stars })
+}
+
+// Asks `<base_url>/<year>`, the event's calendar, who `cookie` belongs to.
+pub async fn check_session_at(base_url: &str, cookie: &SessionCookie) -> Result<User, AocError> {
+    let url = format!("{}/{}", base_url.trim_end_matches('/'), crate::YEAR);
+    let response = Client::shared().get(&url, Some(cookie)).await?;
+
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(error::classify_response(&url, status.as_u16(), None, &body).expired());
+    }
+    parse_user(&body).ok_or(AocError::SessionExpired)
+}
+
+// Checks the session of `profile` against adventofcode.com.
+pub async fn check_session(profile: &Profile) -> Result<User, AocError> {
+    let cookie = profile.cookie().ok_or(AocError::NotLoggedIn)?;
+    check_session_at(crate::AOC_URL, cookie).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_user() {
+        let logged_in = r#"<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1><nav><ul><li><a href="/2023/about">[About]</a></li></ul></nav><div class="user">hitch&amp;hooker <a href="/2023/support" class="supporter-badge" title="Advent of Code Supporter">AoC++</a> <span class="star-count">48*</span></div></div></header>"#;
+        assert_eq!(parse_user(logged_in), Some(User { name: "hitch&hooker".to_string(), stars: Some(48) }));
+
+        let anonymous = r#"<div class="user">(anonymous user #1234567) <span class="star-count">7*</span></div>"#;
+        assert_eq!(parse_user(anonymous).unwrap().name, "(anonymous user #1234567)");
+
+        let logged_out = r#"<header><nav><ul><li><a href="/2023/auth/login">[Log In]</a></li></ul></nav></header>"#;
+        assert_eq!(parse_user(logged_out), None);
+    }
+}
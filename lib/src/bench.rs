@@ -0,0 +1,263 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::error::AocError;
+use crate::solver::Part;
+
+const HEADER: &str = "# day\tpart\tphase\titerations\tmean_ns\tmedian_ns\tstddev_ns\tmeasured_at";
+
+/// What a benchmark timed: parsing the input, or solving from already parsed input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Solve,
+}
+
+impl Phase {
+    fn as_str(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Solve => "solve",
+        }
+    }
+
+    fn from_name(s: &str) -> Option<Self> {
+        match s {
+            "parse" => Some(Phase::Parse),
+            "solve" => Some(Phase::Solve),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        if n == 0 {
+            return Stats { iterations: 0, mean: Duration::ZERO, median: Duration::ZERO, stddev: Duration::ZERO };
+        }
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        Stats {
+            iterations: n,
+            mean: Duration::from_secs_f64(mean),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// How long to keep timing a single phase.
+#[derive(Clone, Copy, Debug)]
+pub struct Budget {
+    pub warmup: usize,
+    pub iterations: usize,
+    /// Stop early once this much time went into measured iterations, so slow
+    /// solvers (04b) don't run for minutes.
+    pub max_time: Duration,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Budget { warmup: 10, iterations: 100, max_time: Duration::from_secs(5) }
+    }
+}
+
+// Runs `f` for the warmup rounds, then times it until the iteration count or
+// the time budget runs out. At least one iteration is always measured.
+pub fn measure<T>(budget: Budget, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..budget.warmup {
+        std::hint::black_box(f());
+    }
+
+    let mut samples = Vec::with_capacity(budget.iterations);
+    let started = Instant::now();
+    while samples.is_empty() || (samples.len() < budget.iterations && started.elapsed() < budget.max_time) {
+        let start = Instant::now();
+        std::hint::black_box(f());
+        samples.push(start.elapsed());
+    }
+    Stats::from_samples(&samples)
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchRecord {
+    pub day: u8,
+    pub part: Part,
+    pub phase: Phase,
+    pub stats: Stats,
+    /// Unix timestamp of the run.
+    pub measured_at: u64,
+}
+
+fn parse_record(line: &str) -> Option<BenchRecord> {
+    let mut fields = line.split('\t');
+    let day = fields.next()?.parse().ok()?;
+    let part = Part::try_from(fields.next()?.parse::<u8>().ok()?).ok()?;
+    let phase = Phase::from_name(fields.next()?)?;
+    let iterations = fields.next()?.parse().ok()?;
+    let mut nanos = || fields.next()?.parse().ok().map(Duration::from_nanos);
+    let stats = Stats { iterations, mean: nanos()?, median: nanos()?, stddev: nanos()? };
+    let measured_at = fields.next()?.parse().ok()?;
+    Some(BenchRecord { day, part, phase, stats, measured_at })
+}
+
+// bench.tsv at the workspace root, unless AOC_BENCH points somewhere else.
+pub fn default_path() -> PathBuf {
+    match std::env::var_os("AOC_BENCH") {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../bench.tsv"),
+    }
+}
+
+/// Every benchmark result ever stored, oldest first.
+pub struct History {
+    path: PathBuf,
+    records: Vec<BenchRecord>,
+}
+
+impl History {
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, AocError> {
+        let path = path.into();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(AocError::io(&path, e)),
+        };
+
+        let mut records = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            records.push(parse_record(line).ok_or_else(|| AocError::CacheCorrupt {
+                path: path.clone(),
+                reason: format!("unreadable benchmark on line {}", i + 1),
+            })?);
+        }
+        Ok(History { path, records })
+    }
+
+    pub fn previous(&self, day: u8, part: Part, phase: Phase) -> Option<&BenchRecord> {
+        self.records
+            .iter()
+            .rev()
+            .find(|r| r.day == day && r.part == part && r.phase == phase)
+    }
+
+    pub fn save(&mut self, day: u8, part: Part, phase: Phase, stats: Stats) -> Result<(), AocError> {
+        let measured_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        let new_file = !self.path.exists();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| AocError::io(&self.path, e))?;
+        let mut line = String::new();
+        if new_file {
+            line.push_str(HEADER);
+            line.push('\n');
+        }
+        line.push_str(&format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+            day,
+            part,
+            phase,
+            stats.iterations,
+            stats.mean.as_nanos(),
+            stats.median.as_nanos(),
+            stats.stddev.as_nanos(),
+            measured_at
+        ));
+        file.write_all(line.as_bytes()).map_err(|e| AocError::io(&self.path, e))?;
+
+        self.records.push(BenchRecord { day, part, phase, stats, measured_at });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples: Vec<Duration> = [2, 4, 4, 4, 5, 5, 7, 9].iter().map(|&ms| Duration::from_millis(ms)).collect();
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.iterations, 8);
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_micros(4500));
+        assert_eq!(stats.stddev, Duration::from_millis(2));
+    }
+
+    #[test]
+    fn test_measure_respects_budget() {
+        let mut calls = 0;
+        let budget = Budget { warmup: 3, iterations: 20, max_time: Duration::from_secs(60) };
+        let stats = measure(budget, || calls += 1);
+        assert_eq!(stats.iterations, 20);
+        assert_eq!(calls, 23);
+
+        let budget = Budget { warmup: 0, iterations: 1000, max_time: Duration::ZERO };
+        assert_eq!(measure(budget, || ()).iterations, 1);
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc2023-bench-{}.tsv", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let stats = Stats::from_samples(&[Duration::from_micros(10), Duration::from_micros(30)]);
+        let mut history = History::load(&path).unwrap();
+        history.save(4, Part::Two, Phase::Solve, stats).unwrap();
+
+        let history = History::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(history.previous(4, Part::Two, Phase::Solve).unwrap().stats, stats);
+        assert!(history.previous(4, Part::Two, Phase::Parse).is_none());
+    }
+}
@@ -0,0 +1,136 @@
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::config::Config;
+use crate::error::AocError;
+use crate::profile::Profile;
+
+/// How an input in the cache was fetched, kept next to it in `input.meta.toml`.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Meta {
+    /// Unix timestamp of the fetch.
+    pub fetched_at: u64,
+    /// HTTP status the site answered with.
+    pub status: u16,
+    /// `sha256:<hex>` of the input, see `checksum`.
+    pub checksum: String,
+}
+
+// Root of the input cache: AOC_CACHE_DIR, else the config's cache_dir, else
+// aoc2023 in the XDG cache directory, else .cache at the workspace root.
+pub fn root(config: &Config) -> PathBuf {
+    root_with(env::var_os("AOC_CACHE_DIR"), config)
+}
+
+// `root` with the value of AOC_CACHE_DIR passed in.
+pub(crate) fn root_with(cache_dir: Option<OsString>, config: &Config) -> PathBuf {
+    let xdg = || match env::var_os("XDG_CACHE_HOME").filter(|dir| Path::new(dir).is_absolute()) {
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => env::var_os("HOME").map(|home| Path::new(&home).join(".cache")),
+    };
+    cache_dir
+        .map(PathBuf::from)
+        .or_else(|| config.cache_dir.clone())
+        .or_else(|| xdg().map(|dir| dir.join("aoc2023")))
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../.cache"))
+}
+
+// `<root>/<year>/<day>/<profile>/input.txt`
+pub fn input_path(root: impl AsRef<Path>, year: u16, day: u8, profile: &str) -> PathBuf {
+    root.as_ref().join(year.to_string()).join(day.to_string()).join(profile).join("input.txt")
+}
+
+// `<root>/<year>/leaderboard/<profile>/<id>.json`
+pub fn leaderboard_path(root: impl AsRef<Path>, year: u16, profile: &str, id: u64) -> PathBuf {
+    root.as_ref().join(year.to_string()).join("leaderboard").join(profile).join(format!("{}.json", id))
+}
+
+// Where a 2023 input was cached before there was a cache root: in the day's "a"
+// crate, e.g. 04a/output/input.txt, or 04a/output/<profile>/input.txt.
+pub fn legacy_input_path(year: u16, day: u8, profile: &Profile) -> Option<PathBuf> {
+    if year != crate::YEAR {
+        return None;
+    }
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../{:02}a/output", day));
+    Some(if profile.is_default() { dir.join("input.txt") } else { dir.join(&profile.name).join("input.txt") })
+}
+
+pub fn meta_path(input: &Path) -> PathBuf {
+    input.with_extension("meta.toml")
+}
+
+pub fn checksum(data: &str) -> String {
+    let digest = Sha256::digest(data.as_bytes());
+    let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("sha256:{}", hex)
+}
+
+impl Meta {
+    // Metadata for `data`, fetched just now.
+    pub fn new(status: u16, data: &str) -> Self {
+        let fetched_at = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        Meta { fetched_at, status, checksum: checksum(data) }
+    }
+
+    // `None` for inputs cached without metadata.
+    pub fn load(input: &Path) -> Result<Option<Self>, AocError> {
+        let path = meta_path(input);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let text = fs::read_to_string(&path).map_err(|e| AocError::io(&path, e))?;
+        let meta = toml::from_str(&text)
+            .map_err(|e| AocError::CacheCorrupt { path: path.clone(), reason: e.message().to_string() })?;
+        Ok(Some(meta))
+    }
+
+    pub fn save(&self, input: &Path) -> Result<(), AocError> {
+        let path = meta_path(input);
+        let text = toml::to_string(self).expect("metadata serializes");
+        crate::save_to_file(&path.to_string_lossy(), &text)
+    }
+}
+
+// Copies an input from its legacy location into the cache, dated by when the
+// legacy file was written.
+pub fn adopt(legacy: &Path, input: &Path) -> Result<(), AocError> {
+    let data = fs::read_to_string(legacy).map_err(|e| AocError::io(legacy, e))?;
+    let mut meta = Meta::new(200, &data);
+    if let Ok(modified) = fs::metadata(legacy).and_then(|m| m.modified()) {
+        meta.fetched_at = modified.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    }
+    meta.save(input)?;
+    crate::save_to_file(&input.to_string_lossy(), &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_and_meta() {
+        let root = std::env::temp_dir().join(format!("aoc2023-cache-{}", std::process::id()));
+        let input = input_path(&root, 2015, 7, "alice");
+        assert!(input.ends_with("2015/7/alice/input.txt"));
+        assert!(meta_path(&input).ends_with("2015/7/alice/input.meta.toml"));
+        assert!(leaderboard_path(&root, 2015, "alice", 1001).ends_with("2015/leaderboard/alice/1001.json"));
+
+        let profile = Profile::select(&Config::default(), Some("default")).unwrap();
+        assert_eq!(legacy_input_path(2015, 7, &profile), None);
+        assert!(legacy_input_path(2023, 4, &profile).unwrap().ends_with("04a/output/input.txt"));
+
+        assert_eq!(Meta::load(&input).unwrap(), None);
+        let meta = Meta::new(200, "1abc2\n");
+        meta.save(&input).unwrap();
+        assert_eq!(Meta::load(&input).unwrap(), Some(meta));
+        assert_eq!(checksum(""), "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,205 @@
+use std::env;
+use std::fs::OpenOptions;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::{HeaderValue, COOKIE};
+use reqwest::{RequestBuilder, Response};
+
+use crate::config::{self, Config};
+use crate::error::AocError;
+use crate::session::SessionCookie;
+
+const REPOSITORY: &str = "github.com/hitchhooker/aoc2023";
+const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// HTTP client shared by everything that talks to adventofcode.com.
+///
+/// Every request is appended to a request log, and requests are spaced at least
+/// `min_interval` apart, across processes, as the site's automation guidelines ask.
+pub struct Client {
+    http: reqwest::Client,
+    request_log: PathBuf,
+    min_interval: Duration,
+}
+
+// AOC_USER_AGENT replaces the whole header, AOC_CONTACT or the `contact` of
+// aoc.toml (an email or handle) is appended to the repository URL so the site
+// can reach whoever runs this.
+pub fn user_agent() -> String {
+    if let Ok(agent) = env::var("AOC_USER_AGENT") {
+        return agent;
+    }
+    let contact = env::var("AOC_CONTACT")
+        .ok()
+        .or_else(|| Config::load(config::default_path()).ok()?.contact);
+    match contact {
+        Some(contact) => format!("{} by {}", REPOSITORY, contact),
+        None => REPOSITORY.to_string(),
+    }
+}
+
+// requests.log at the workspace root, unless AOC_REQUEST_LOG points somewhere else.
+pub fn default_request_log() -> PathBuf {
+    match env::var_os("AOC_REQUEST_LOG") {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../requests.log"),
+    }
+}
+
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+// Turns every request into an `AocError::Offline`, so only cached data is used.
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+// Offline when switched on above, when AOC_OFFLINE is set or when the binary was
+// started with `--offline`.
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed) || env::var_os("AOC_OFFLINE").is_some() || env::args().any(|arg| arg == "--offline")
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+// Waits until `min_interval` has passed since the last request in the log, then
+// logs this one. The log stays locked meanwhile, so concurrent processes queue up
+// behind each other instead of all firing at once.
+pub fn wait_turn(log: &Path, min_interval: Duration, method: &str, url: &str) -> Result<(), AocError> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .read(true)
+        .append(true)
+        .open(log)
+        .map_err(|e| AocError::io(log, e))?;
+    file.lock().map_err(|e| AocError::io(log, e))?;
+
+    let mut text = String::new();
+    file.read_to_string(&mut text).map_err(|e| AocError::io(log, e))?;
+    let last = text
+        .lines()
+        .rev()
+        .find_map(|line| line.split('\t').next()?.parse::<u64>().ok());
+
+    if let Some(last) = last {
+        // The log keeps whole milliseconds, the last request may have gone out up
+        // to one later than its entry says.
+        let next = last + min_interval.as_millis() as u64 + 1;
+        let mut now = unix_millis();
+        if now < next {
+            tracing::debug!("waiting {}ms before {} {}", next - now, method, url);
+        }
+        while now < next {
+            thread::sleep(Duration::from_millis(next - now));
+            now = unix_millis();
+        }
+    }
+
+    writeln!(file, "{}\t{}\t{}", unix_millis(), method, url).map_err(|e| AocError::io(log, e))
+}
+
+// Marked sensitive, so the header is redacted wherever reqwest prints the request.
+fn cookie_header(cookie: &SessionCookie) -> HeaderValue {
+    let mut value = HeaderValue::from_str(&cookie.header()).unwrap_or_else(|_| HeaderValue::from_static(""));
+    value.set_sensitive(true);
+    value
+}
+
+// Requests to our own machine (the stand-in servers of the tests) are neither
+// throttled nor logged.
+fn is_local(url: &str) -> bool {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(|host| host == "localhost" || host == "127.0.0.1"))
+        .unwrap_or(false)
+}
+
+impl Client {
+    pub fn new(user_agent: &str, request_log: PathBuf, min_interval: Duration) -> Result<Self, AocError> {
+        let http = reqwest::Client::builder().user_agent(user_agent).build()?;
+        Ok(Client { http, request_log, min_interval })
+    }
+
+    // Built on first use from the environment: see `user_agent`, `default_request_log`
+    // and AOC_MIN_REQUEST_INTERVAL (seconds between requests, 3 by default).
+    pub fn shared() -> &'static Client {
+        static CLIENT: OnceLock<Client> = OnceLock::new();
+        CLIENT.get_or_init(|| {
+            let min_interval = env::var("AOC_MIN_REQUEST_INTERVAL")
+                .ok()
+                .and_then(|secs| secs.parse().ok())
+                .map_or(DEFAULT_MIN_INTERVAL, Duration::from_secs_f64);
+            Client::new(&user_agent(), default_request_log(), min_interval).expect("failed to build the HTTP client")
+        })
+    }
+
+    async fn send(&self, request: RequestBuilder, method: &str, url: &str) -> Result<Response, AocError> {
+        if is_offline() {
+            return Err(AocError::Offline { url: url.to_string() });
+        }
+        if !is_local(url) {
+            let (log, min_interval) = (self.request_log.clone(), self.min_interval);
+            let (method, url) = (method.to_string(), url.to_string());
+            tokio::task::spawn_blocking(move || wait_turn(&log, min_interval, &method, &url))
+                .await
+                .expect("request log task panicked")?;
+        }
+        tracing::debug!("{} {}", method, url);
+        let response = request.send().await?;
+        tracing::debug!("{} {} -> {}", method, url, response.status());
+        Ok(response)
+    }
+
+    // Public pages (puzzle descriptions) can be fetched without a cookie.
+    pub async fn get(&self, url: &str, cookie: Option<&SessionCookie>) -> Result<Response, AocError> {
+        let mut request = self.http.get(url);
+        if let Some(cookie) = cookie {
+            request = request.header(COOKIE, cookie_header(cookie));
+        }
+        self.send(request, "GET", url).await
+    }
+
+    pub async fn post_form(&self, url: &str, cookie: &SessionCookie, form: &[(&str, &str)]) -> Result<Response, AocError> {
+        let request = self.http.post(url).header(COOKIE, cookie_header(cookie)).form(form);
+        self.send(request, "POST", url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::time::Instant;
+
+    #[test]
+    fn test_wait_turn_spaces_requests() {
+        let log = env::temp_dir().join(format!("aoc2023-requests-{}.log", std::process::id()));
+        let _ = fs::remove_file(&log);
+        let url = "https://adventofcode.com/2023/day/1/input";
+
+        let start = Instant::now();
+        wait_turn(&log, Duration::from_millis(300), "GET", url).unwrap();
+        assert!(start.elapsed() < Duration::from_millis(300));
+        wait_turn(&log, Duration::from_millis(300), "GET", url).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+
+        let text = fs::read_to_string(&log).unwrap();
+        fs::remove_file(&log).unwrap();
+        assert_eq!(text.lines().count(), 2);
+        assert!(text.lines().all(|line| line.ends_with("\tGET\thttps://adventofcode.com/2023/day/1/input")));
+    }
+
+    #[test]
+    fn test_is_local() {
+        assert!(is_local("http://127.0.0.1:9/2023/day/1/input"));
+        assert!(!is_local("https://adventofcode.com/2023/day/1/input"));
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error::AocError;
+use crate::session;
+
+/// Settings shared by the runner and the day binaries, read from `aoc.toml`.
+///
+/// ```toml
+/// contact = "you@example.com"
+/// default_profile = "alice"
+/// leaderboard = 123456
+/// cache_dir = "/var/cache/aoc"
+///
+/// [profiles.alice]
+/// session_file = "/home/alice/.config/aoc/session"
+///
+/// [profiles.bob]
+/// session = "53616c..."
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Appended to the User-Agent, so the site can reach whoever runs this.
+    pub contact: Option<String>,
+    /// Profile used when none is picked with `--profile` or AOC_PROFILE.
+    pub default_profile: Option<String>,
+    /// Id of the private leaderboard `aoc leaderboard` shows by default.
+    pub leaderboard: Option<u64>,
+    /// Root of the input cache, relative to the config file unless absolute.
+    pub cache_dir: Option<PathBuf>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, ProfileConfig>,
+}
+
+/// Where a profile's session cookie comes from, exactly one of the two.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileConfig {
+    /// Value of the adventofcode.com `session` cookie, with or without the `session=` prefix.
+    pub session: Option<String>,
+    /// File holding the cookie, relative to the config file unless absolute.
+    pub session_file: Option<PathBuf>,
+}
+
+// aoc.toml at the workspace root, unless AOC_CONFIG points somewhere else.
+pub fn default_path() -> PathBuf {
+    match std::env::var_os("AOC_CONFIG") {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../aoc.toml"),
+    }
+}
+
+impl Config {
+    // A missing file is an empty config, everything then comes from the environment.
+    // A config with sessions written into it gets the same permission check as a
+    // session file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(AocError::io(path, e)),
+        };
+        let invalid = |reason: String| AocError::Config { path: path.to_path_buf(), reason };
+        let mut config: Config = toml::from_str(&text).map_err(|e| invalid(e.message().to_string()))?;
+        let relative_to_config = |file: &Path| path.parent().unwrap_or(Path::new("")).join(file);
+
+        if let Some(dir) = config.cache_dir.as_mut().filter(|dir| dir.is_relative()) {
+            *dir = relative_to_config(dir);
+        }
+
+        for (name, profile) in config.profiles.iter_mut() {
+            match (&profile.session, &mut profile.session_file) {
+                (Some(_), None) => session::check_permissions(path)?,
+                (None, Some(file)) if file.is_relative() => {
+                    *file = relative_to_config(file);
+                }
+                (None, Some(_)) => {}
+                _ => return Err(invalid(format!("profile `{}` needs exactly one of session and session_file", name))),
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_load() {
+        let path = std::env::temp_dir().join(format!("aoc2023-config-{}.toml", std::process::id()));
+        let _ = fs::remove_file(&path);
+        assert_eq!(Config::load(&path).unwrap(), Config::default());
+
+        fs::write(&path, "default_profile = \"bob\"\ncache_dir = \"aoc-cache\"\n\n[profiles.bob]\nsession_file = \"bob.session\"\n").unwrap();
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.default_profile.as_deref(), Some("bob"));
+        assert_eq!(config.cache_dir, Some(std::env::temp_dir().join("aoc-cache")));
+        assert_eq!(config.profiles["bob"].session_file, Some(std::env::temp_dir().join("bob.session")));
+
+        fs::write(&path, "[profiles.bob]\n").unwrap();
+        assert!(matches!(Config::load(&path), Err(AocError::Config { .. })));
+
+        fs::write(&path, "[profiles.bob]\ncookie = \"abc\"\n").unwrap();
+        let result = Config::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(result, Err(AocError::Config { .. })));
+    }
+}
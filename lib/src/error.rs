@@ -0,0 +1,216 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::solver::Part;
+
+/// Everything that can go wrong while talking to adventofcode.com or the local cache.
+#[derive(Debug)]
+pub enum AocError {
+    /// No session cookie, or the site refused the one we sent.
+    NotLoggedIn,
+    /// We sent a session cookie, but the site treated us as logged out.
+    SessionExpired,
+    /// `--profile` (or AOC_PROFILE) names a profile the config doesn't have.
+    UnknownProfile { name: String },
+    /// A file holding a session cookie can be read by other users.
+    InsecureSecret { path: PathBuf, mode: u32 },
+    /// The config file exists but can't be read as one.
+    Config { path: PathBuf, reason: String },
+    /// The server says the puzzle (or its input) is not available yet.
+    NotUnlocked { url: String },
+    /// Refused locally: the puzzle unlocks in `remaining`.
+    Locked { year: u16, day: u8, remaining: Duration },
+    /// There is no puzzle for this year and day.
+    NoSuchPuzzle { year: u16, day: u8 },
+    /// The site asked us to slow down.
+    RateLimited { retry_after: Option<Duration> },
+    /// Writing the cache failed because the disk is full.
+    DiskFull { path: PathBuf },
+    /// Any other filesystem error.
+    Io { path: PathBuf, source: io::Error },
+    /// A cached file exists but can't be trusted.
+    CacheCorrupt { path: PathBuf, reason: String },
+    /// Offline mode is on and what we need isn't cached.
+    Offline { url: String },
+    /// The request never got a response.
+    Http(reqwest::Error),
+    /// The site answered with a status we don't know how to handle.
+    Status { status: u16, body: String },
+    /// The private leaderboard exists, but the session's account isn't on it.
+    NoLeaderboardAccess { id: u64 },
+    /// A solver no longer produces the answer recorded as correct in the ledger.
+    Regression { day: u8, part: Part, expected: String, actual: String },
+}
+
+impl AocError {
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        let path = path.into();
+        match source.kind() {
+            io::ErrorKind::StorageFull => AocError::DiskFull { path },
+            _ => AocError::Io { path, source },
+        }
+    }
+
+    // A login page in answer to a request that carried a session cookie means
+    // the cookie expired, which needs a different fix than not having one.
+    pub fn expired(self) -> Self {
+        match self {
+            AocError::NotLoggedIn => AocError::SessionExpired,
+            e => e,
+        }
+    }
+
+    // Whether trying the same request again later can succeed without the user
+    // doing anything.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            AocError::RateLimited { .. } => true,
+            AocError::Http(e) => e.is_timeout() || e.is_connect(),
+            AocError::Status { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+// Puts a response that isn't a puzzle answer or input into one of the variants
+// above, based on the status code and the text adventofcode.com sends with it.
+pub fn classify_response(url: &str, status: u16, retry_after: Option<Duration>, body: &str) -> AocError {
+    match status {
+        429 => AocError::RateLimited { retry_after },
+        400 | 401 | 403 if body.contains("log in") => AocError::NotLoggedIn,
+        404 if body.contains("before it unlocks") => AocError::NotUnlocked { url: url.to_string() },
+        _ => AocError::Status { status, body: body.chars().take(200).collect() },
+    }
+}
+
+// Recognises the pages adventofcode.com serves in place of puzzle input, even
+// with a 200 status, so they are never mistaken for (or cached as) input.
+pub fn input_error_page(url: &str, body: &str) -> Option<AocError> {
+    // Logged out HTML pages link to the login page from their header.
+    if body.contains("Please log in") || body.contains("/auth/login") {
+        Some(AocError::NotLoggedIn)
+    } else if body.contains("before it unlocks") {
+        Some(AocError::NotUnlocked { url: url.to_string() })
+    } else if body.trim_start().starts_with('<') {
+        // Puzzle inputs are plain text, an HTML document means we got a web page instead.
+        Some(AocError::Status { status: 200, body: body.chars().take(200).collect() })
+    } else {
+        None
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::NotLoggedIn => {
+                write!(f, "not logged in: set SESSION_COOKIE, or the session of your profile in aoc.toml, to the session cookie of your adventofcode.com login")
+            }
+            AocError::SessionExpired => write!(
+                f,
+                "session expired: log in to adventofcode.com again and update the session cookie, `aoc auth check` tells whether it works"
+            ),
+            AocError::UnknownProfile { name } => {
+                write!(f, "unknown profile `{}`, add a [profiles.{}] section with its session to aoc.toml", name, name)
+            }
+            AocError::InsecureSecret { path, mode } => write!(
+                f,
+                "{} holds a session cookie but is readable by other users (mode {:o}), run `chmod 600 {}`",
+                path.display(),
+                mode,
+                path.display()
+            ),
+            AocError::Config { path, reason } => write!(f, "invalid config {}: {}", path.display(), reason),
+            AocError::NotUnlocked { url } => write!(f, "{} is not unlocked yet, try again after midnight EST", url),
+            AocError::Locked { year, day, remaining } => {
+                write!(f, "day {} of {} unlocks in {}, not asking the server yet", day, year, crate::unlock::format_remaining(*remaining))
+            }
+            AocError::NoSuchPuzzle { year, day } => write!(f, "there is no puzzle for day {} of {}", day, year),
+            AocError::RateLimited { retry_after: Some(wait) } => {
+                write!(f, "rate limited by adventofcode.com, retry in {}s", wait.as_secs())
+            }
+            AocError::RateLimited { retry_after: None } => write!(f, "rate limited by adventofcode.com, retry later"),
+            AocError::DiskFull { path } => write!(f, "disk full while writing {}", path.display()),
+            AocError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            AocError::CacheCorrupt { path, reason } => {
+                write!(f, "cached file {} is corrupt ({}), delete it to fetch again", path.display(), reason)
+            }
+            AocError::Offline { url } => write!(f, "offline, and {} is not cached", url),
+            AocError::Http(e) => write!(f, "request failed: {}", e),
+            AocError::Status { status, body } => write!(f, "unexpected HTTP {}: {}", status, body.trim()),
+            AocError::NoLeaderboardAccess { id } => {
+                write!(f, "no access to private leaderboard {}, join it with its code first", id)
+            }
+            AocError::Regression { day, part, expected, actual } => {
+                write!(f, "day {} part {} answered {}, but the correct answer is {}", day, part, actual, expected)
+            }
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            AocError::Http(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for AocError {
+    fn from(e: reqwest::Error) -> Self {
+        AocError::Http(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_response() {
+        let url = "https://adventofcode.com/2023/day/25/input";
+
+        let err = classify_response(url, 400, None, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n");
+        assert!(matches!(err, AocError::NotLoggedIn));
+
+        let err = classify_response(url, 404, None, "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n");
+        assert!(matches!(err, AocError::NotUnlocked { .. }));
+
+        let err = classify_response(url, 429, Some(Duration::from_secs(60)), "");
+        assert!(matches!(err, AocError::RateLimited { .. }) && err.is_retryable());
+
+        let err = classify_response(url, 500, None, "Internal Server Error");
+        assert!(matches!(err, AocError::Status { status: 500, .. }) && err.is_retryable());
+    }
+
+    #[test]
+    fn test_input_error_page() {
+        let url = "https://adventofcode.com/2023/day/5/input";
+        assert!(matches!(
+            input_error_page(url, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Some(AocError::NotLoggedIn)
+        ));
+        assert!(matches!(
+            input_error_page(url, "Please don't repeatedly request this endpoint before it unlocks!"),
+            Some(AocError::NotUnlocked { .. })
+        ));
+        assert!(input_error_page(url, "<!DOCTYPE html>\n<html lang=\"en-us\">").is_some());
+        assert!(matches!(
+            input_error_page(url, "<html><header><a href=\"/2023/auth/login\">[Log In]</a></header></html>").map(AocError::expired),
+            Some(AocError::SessionExpired)
+        ));
+        assert!(input_error_page(url, "seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98 2\n").is_none());
+    }
+
+    #[test]
+    fn test_io_disk_full() {
+        let err = AocError::io("output/input.txt", io::Error::from(io::ErrorKind::StorageFull));
+        assert!(matches!(err, AocError::DiskFull { .. }));
+        let err = AocError::io("output/input.txt", io::Error::from(io::ErrorKind::PermissionDenied));
+        assert!(matches!(err, AocError::Io { .. }) && !err.is_retryable());
+    }
+}
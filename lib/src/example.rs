@@ -0,0 +1,107 @@
+use std::fs;
+use std::path::Path;
+
+use crate::error::AocError;
+use crate::html::{between, strip_tags};
+use crate::session::SessionCookie;
+use crate::solver::Part;
+
+/// The example of one part of a puzzle, as given in its description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part: Part,
+    pub input: String,
+    /// The emphasised answer the description gives for the example, if any.
+    pub answer: Option<String>,
+}
+
+// Puzzle description page for the given day.
+pub fn puzzle_url(day: u8) -> String {
+    format!("{}/{}/day/{}", crate::AOC_URL, crate::YEAR, day)
+}
+
+// Reads the description page from `local_path`, or fetches and caches it. Pass
+// `refresh` after solving part 1, the part 2 description only shows up then.
+pub async fn get_puzzle_page(
+    day: u8,
+    cookie: Option<&SessionCookie>,
+    local_path: &str,
+    refresh: bool,
+) -> Result<String, AocError> {
+    if !refresh && Path::new(local_path).exists() {
+        return fs::read_to_string(local_path).map_err(|e| AocError::io(local_path, e));
+    }
+    crate::unlock::ensure_unlocked(crate::YEAR, day)?;
+    let page = crate::fetch_url(&puzzle_url(day), cookie).await?;
+    crate::save_to_file(local_path, &page)?;
+    Ok(page)
+}
+
+// Each part's description is its own `<article class="day-desc">`. The example
+// is the first multi-line `<pre><code>` block in it, and the expected answer is
+// the last `<code><em>` of the description. Part two often refers back to the
+// example of part one instead of repeating it.
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+
+    for (article, part) in between(html, "<article class=\"day-desc\">", "</article>").into_iter().zip(Part::ALL) {
+        let input = between(article, "<pre><code>", "</code></pre>")
+            .into_iter()
+            .map(strip_tags)
+            .find(|block| block.trim_end().contains('\n'))
+            .or_else(|| examples.last().map(|e| e.input.clone()));
+        let Some(input) = input else { continue };
+
+        let answer = between(article, "<code><em>", "</em></code>").last().map(|a| strip_tags(a));
+        examples.push(Example { part, input, answer });
+    }
+    examples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>Here, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54331</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Equipped with this new information, you now need to find the real first and last digit on each line. For example:</p>
+<pre><code>two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+</code></pre>
+<p>In this example, the calibration values are <code>29</code>, <code>83</code>, <code>13</code>, <code>24</code>, <code>42</code>, <code>14</code>, and <code>76</code>. Adding these together produces <code><em>281</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract_examples() {
+        let examples = extract_examples(PAGE);
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].part, Part::One);
+        assert_eq!(examples[0].input, "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n");
+        assert_eq!(examples[0].answer.as_deref(), Some("142"));
+        assert_eq!(examples[1].part, Part::Two);
+        assert!(examples[1].input.starts_with("two1nine\n"));
+        assert_eq!(examples[1].answer.as_deref(), Some("281"));
+
+        let reused = PAGE.replace("<pre><code>two1nine", "<p>two1nine").replace("7pqrstsixteen\n</code></pre>", "</p>");
+        let examples = extract_examples(&reused);
+        assert_eq!(examples[1].input, examples[0].input);
+        assert_eq!(examples[1].answer.as_deref(), Some("281"));
+    }
+}
@@ -0,0 +1,341 @@
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Sub};
+use std::str::FromStr;
+
+use crate::parse::{ParseError, Span};
+
+/// A cell position, `x` counting columns to the right and `y` rows down from the
+/// top left corner. Signed, so stepping off the grid is just another point that
+/// `Grid::get` says isn't there.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const UP: Point = Point::new(0, -1);
+    pub const DOWN: Point = Point::new(0, 1);
+    pub const LEFT: Point = Point::new(-1, 0);
+    pub const RIGHT: Point = Point::new(1, 0);
+    pub const UP_LEFT: Point = Point::new(-1, -1);
+    pub const UP_RIGHT: Point = Point::new(1, -1);
+    pub const DOWN_LEFT: Point = Point::new(-1, 1);
+    pub const DOWN_RIGHT: Point = Point::new(1, 1);
+
+    /// The 4 orthogonal steps, clockwise from up.
+    pub const ORTHOGONAL: [Point; 4] = [Point::UP, Point::RIGHT, Point::DOWN, Point::LEFT];
+    /// All 8 steps to a neighbouring cell, row by row.
+    pub const ALL: [Point; 8] = [
+        Point::UP_LEFT,
+        Point::UP,
+        Point::UP_RIGHT,
+        Point::LEFT,
+        Point::RIGHT,
+        Point::DOWN_LEFT,
+        Point::DOWN,
+        Point::DOWN_RIGHT,
+    ];
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// A rectangle of cells, stored flat in row-major order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // `None` unless there are exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        (cells.len() == width * height).then_some(Grid { width, height, cells })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    // One cell per character, every line as wide as the first. Trailing newlines
+    // don't make a row.
+    pub fn parse_with(text: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(text.len());
+        let mut width = 0;
+        let mut height = 0;
+        for (i, line) in Span::new(text.trim_end_matches(['\n', '\r'])).lines().enumerate() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let line_width = cells.len() - before;
+            if i == 0 {
+                width = line_width;
+            } else if line_width != width {
+                // Points at what sticks out, or at the end of a line that's short.
+                let cut = line.char_indices().nth(width).map_or(line.len(), |(at, _)| at);
+                return Err(line.slice(cut, line.len()).error(format!("a line {} wide like the first", width)));
+            }
+            height += 1;
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (0..self.width as i64).contains(&p.x) && (0..self.height as i64).contains(&p.y)
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        self.contains(p).then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i64, (index / self.width) as i64)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    // Every point, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point_of(i))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().enumerate().map(|(i, cell)| (self.point_of(i), cell))
+    }
+
+    // Points whose cell `matches`, row by row.
+    pub fn find_all<'a>(&'a self, mut matches: impl FnMut(&T) -> bool + 'a) -> impl Iterator<Item = Point> + 'a {
+        self.iter().filter(move |(_, cell)| matches(cell)).map(|(p, _)| p)
+    }
+
+    // The cells one `step` away from `p`, for each step, that are on the grid.
+    fn around<'a>(&'a self, p: Point, steps: &'a [Point]) -> impl Iterator<Item = (Point, &'a T)> {
+        steps.iter().filter_map(move |&step| {
+            let q = p + step;
+            self.get(q).map(|cell| (q, cell))
+        })
+    }
+
+    // Up, right, down and left of `p`, as far as they're on the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.around(p, &Point::ORTHOGONAL)
+    }
+
+    // All 8 cells around `p`, diagonals included, as far as they're on the grid.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.around(p, &Point::ALL)
+    }
+
+    // From `start` on, `step` at a time, until the edge.
+    pub fn ray(&self, start: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        std::iter::successors(Some(start), move |&p| Some(p + step))
+            .map_while(move |p| self.get(p).map(|cell| (p, cell)))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // Every diagonal running down and to the right, starting with the one in the
+    // bottom left corner and ending with the one in the top right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        let starts = (0..self.height as i64).rev().map(|y| Point::new(0, y));
+        let starts = starts.chain((1..self.width as i64).map(|x| Point::new(x, 0)));
+        starts.map(|start| self.ray(start, Point::DOWN_RIGHT))
+    }
+
+    // Every diagonal running down and to the left, starting with the one in the
+    // top left corner and ending with the one in the bottom right corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        let last = self.width as i64 - 1;
+        let starts = (0..self.width as i64).map(|x| Point::new(x, 0));
+        let starts = starts.chain((1..self.height as i64).map(move |y| Point::new(last, y)));
+        starts.map(|start| self.ray(start, Point::DOWN_LEFT))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    // Built from `cell(x, y)` of the new grid, `width` by `height` of them.
+    fn from_fn(width: usize, height: usize, cell: impl Fn(usize, usize) -> T) -> Self {
+        let cells = (0..width * height).map(|i| cell(i % width, i / width)).collect();
+        Grid { width, height, cells }
+    }
+
+    // Rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |x, y| self.cells[x * self.width + y].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let h = self.height;
+        Grid::from_fn(h, self.width, |x, y| self.cells[(h - 1 - x) * self.width + y].clone())
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let w = self.width;
+        Grid::from_fn(self.height, w, |x, y| self.cells[x * w + (w - 1 - y)].clone())
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(text, |c| c)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p).unwrap_or_else(|| panic!("{} is off the {}x{} grid", p, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p).unwrap_or_else(|| panic!("{} is off the {}x{} grid", p, width, height))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_get() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid[Point::new(1, 0)], 'b');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        let err = "ab\nc\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, ""));
+        assert_eq!("ab\ncde".parse::<Grid<char>>().unwrap_err().found, "e");
+        assert_eq!(Grid::parse_with("12\n34", |c| c.to_digit(10).unwrap()).unwrap().row(1), &[3, 4]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        let corner: Vec<char> = grid.neighbours8(Point::new(0, 0)).map(|(_, &c)| c).collect();
+        assert_eq!(corner, ['b', 'd', 'e']);
+        let middle: Vec<char> = grid.neighbours4(Point::new(1, 1)).map(|(_, &c)| c).collect();
+        assert_eq!(middle, ['b', 'f', 'd']);
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = grid();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [&['a', 'b', 'c'], &['d', 'e', 'f']]);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.columns().map(|c| c.collect::<String>()).collect::<Vec<_>>(), ["ad", "be", "cf"]);
+
+        let diagonals: Vec<String> = grid.diagonals().map(|d| d.map(|(_, c)| c).collect()).collect();
+        assert_eq!(diagonals, ["d", "ae", "bf", "c"]);
+        let anti: Vec<String> = grid.anti_diagonals().map(|d| d.map(|(_, c)| c).collect()).collect();
+        assert_eq!(anti, ["a", "bd", "ce", "f"]);
+        assert_eq!(grid.ray(Point::new(2, 1), Point::LEFT).map(|(_, c)| c).collect::<String>(), "fed");
+    }
+
+    #[test]
+    fn test_transform_and_find() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+
+        let vowels: Vec<Point> = grid.find_all(|c| "aeiou".contains(*c)).collect();
+        assert_eq!(vowels, [Point::new(0, 0), Point::new(1, 1)]);
+        let mut seen = grid.map(|_| false);
+        seen[Point::new(2, 1)] = true;
+        assert_eq!(seen.find_all(|&s| s).collect::<Vec<_>>(), [Point::new(2, 1)]);
+    }
+}
@@ -0,0 +1,52 @@
+// Just enough HTML handling to read adventofcode.com pages, which are simple and
+// stable enough not to need a real parser.
+
+pub fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    decode_entities(&text)
+}
+
+// Contents of every `<open>...</close>` pair in `html`, in order.
+pub fn between<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        let after = &rest[start + open.len()..];
+        let Some(end) = after.find(close) else { break };
+        found.push(&after[..end]);
+        rest = &after[end + close.len()..];
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_tags_decodes_entities() {
+        assert_eq!(strip_tags("<em>#</em>.&lt;&gt;&amp;"), "#.<>&");
+    }
+
+    #[test]
+    fn test_between() {
+        assert_eq!(between("<b>1</b> <b>2</b> <b>3", "<b>", "</b>"), vec!["1", "2"]);
+    }
+}
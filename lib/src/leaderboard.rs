@@ -0,0 +1,222 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use serde::Deserialize;
+
+use crate::client::Client;
+use crate::error::{self, AocError};
+use crate::profile::Profile;
+use crate::session::SessionCookie;
+use crate::solver::Part;
+
+/// The site asks to fetch a private leaderboard at most once every 15 minutes.
+pub const MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+/// A private leaderboard, as served by `/<year>/leaderboard/private/view/<id>.json`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    /// Keyed by member id.
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// `None` for anonymous users.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    #[serde(default)]
+    pub global_score: u32,
+    /// Unix timestamp of the latest star, 0 without any.
+    pub last_star_ts: u64,
+    /// Day, then part, both as strings, to when the star was earned.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct Star {
+    /// Unix timestamp of when the star was earned.
+    pub get_star_ts: u64,
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+impl Member {
+    // Shown the way the site shows it.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn star_ts(&self, day: u8, part: Part) -> Option<u64> {
+        let star = self.completion_day_level.get(&day.to_string())?.get(&part.to_string())?;
+        Some(star.get_star_ts)
+    }
+
+    // How long part two took after part one, for days with both stars.
+    pub fn part2_delta(&self, day: u8) -> Option<Duration> {
+        let one = self.star_ts(day, Part::One)?;
+        let two = self.star_ts(day, Part::Two)?;
+        Some(Duration::from_secs(two.saturating_sub(one)))
+    }
+}
+
+impl Leaderboard {
+    // Highest local score first, ties go to whoever got their last star earlier.
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|m| (std::cmp::Reverse(m.local_score), m.last_star_ts, m.id));
+        members
+    }
+
+    // Last day anyone on the board has a star for.
+    pub fn last_day(&self) -> u8 {
+        self.members
+            .values()
+            .flat_map(|m| m.completion_day_level.keys())
+            .filter_map(|day| day.parse().ok())
+            .max()
+            .unwrap_or(0)
+    }
+
+    // One row per member, and per day how long part two took after part one:
+    // `*` with only part one done, blank with neither.
+    pub fn render(&self) -> String {
+        let days = 1..=self.last_day();
+        let width = self.members.values().map(|m| m.display_name().chars().count()).max().unwrap_or(0).max(4);
+
+        let mut out = format!("{:>3} {:>5} {:>5}  {:<width$}", "#", "score", "stars", "name", width = width);
+        for day in days.clone() {
+            out.push_str(&format!(" {:>7}", day));
+        }
+        out.push('\n');
+
+        for (rank, member) in self.ranked().into_iter().enumerate() {
+            out.push_str(&format!(
+                "{:>3} {:>5} {:>5}  {:<width$}",
+                rank + 1,
+                member.local_score,
+                member.stars,
+                member.display_name(),
+                width = width
+            ));
+            for day in days.clone() {
+                let cell = match (member.part2_delta(day), member.star_ts(day, Part::One)) {
+                    (Some(delta), _) => crate::unlock::format_remaining(delta),
+                    (None, Some(_)) => "*".to_string(),
+                    (None, None) => String::new(),
+                };
+                out.push_str(&format!(" {:>7}", cell));
+            }
+            let trimmed = out.trim_end().len();
+            out.truncate(trimmed);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+pub fn parse(json: &str) -> Result<Leaderboard, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+fn is_fresh(path: &Path, max_age: Duration) -> bool {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < max_age)
+}
+
+// Reads the leaderboard from `cache_path` while it's younger than MAX_AGE (or at
+// any age when offline), and fetches `<base_url>/<year>/leaderboard/private/view/<id>.json`
+// otherwise.
+pub async fn get_leaderboard_at(
+    base_url: &str,
+    cookie: &SessionCookie,
+    year: u16,
+    id: u64,
+    cache_path: &Path,
+) -> Result<Leaderboard, AocError> {
+    if is_fresh(cache_path, MAX_AGE) || (crate::client::is_offline() && cache_path.exists()) {
+        let cached = fs::read_to_string(cache_path).map_err(|e| AocError::io(cache_path, e))?;
+        return parse(&cached).map_err(|e| AocError::CacheCorrupt { path: cache_path.into(), reason: e.to_string() });
+    }
+
+    let url = format!("{}/{}/leaderboard/private/view/{}.json", base_url.trim_end_matches('/'), year, id);
+    let response = Client::shared().get(&url, Some(cookie)).await?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(error::classify_response(&url, status.as_u16(), None, &body).expired());
+    }
+
+    // Without access the site redirects to an HTML page instead of sending JSON.
+    let leaderboard = match parse(&body) {
+        Ok(leaderboard) => leaderboard,
+        Err(_) if crate::auth::parse_user(&body).is_none() => return Err(AocError::SessionExpired),
+        Err(_) => return Err(AocError::NoLeaderboardAccess { id }),
+    };
+    crate::save_to_file(&cache_path.to_string_lossy(), &body)?;
+    Ok(leaderboard)
+}
+
+// What a leaderboard shows depends on who asks, so each profile caches its own.
+pub async fn get_leaderboard(profile: &Profile, id: u64) -> Result<Leaderboard, AocError> {
+    let cookie = profile.cookie().ok_or(AocError::NotLoggedIn)?;
+    let cache_path = profile.leaderboard_path(crate::YEAR, id);
+    get_leaderboard_at(crate::AOC_URL, cookie, crate::YEAR, id, &cache_path).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../fixtures/2023/leaderboard/private/view/1001.json");
+
+    #[test]
+    fn test_parse_and_rank() {
+        let board = parse(FIXTURE).unwrap();
+        assert_eq!(board.event, "2023");
+        assert_eq!(board.members.len(), 4);
+
+        let ranked: Vec<String> = board.ranked().iter().map(|m| m.display_name()).collect();
+        assert_eq!(ranked, ["alice", "bob", "(anonymous user #1003)", "carol"]);
+
+        let bob = &board.members["1002"];
+        assert_eq!(bob.star_ts(1, Part::Two), Some(1701408042));
+        assert_eq!(bob.part2_delta(1), Some(Duration::from_secs(42)));
+        assert_eq!(bob.part2_delta(2), None);
+        assert_eq!(board.last_day(), 3);
+    }
+
+    #[test]
+    fn test_render() {
+        let table = parse(FIXTURE).unwrap().render();
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines[0], "  # score stars  name                         1       2       3");
+        assert_eq!(lines[1], "  1    17     6  alice                    5m 0s   1h 0m  1m 30s");
+        assert_eq!(lines[2], "  2     9     3  bob                        42s       *");
+        assert_eq!(lines[3], "  3     1     1  (anonymous user #1003)       *");
+        assert_eq!(lines[4], "  4     0     0  carol");
+    }
+
+    #[test]
+    fn test_cache_freshness() {
+        let path = std::env::temp_dir().join(format!("aoc2023-leaderboard-{}.json", std::process::id()));
+        fs::write(&path, FIXTURE).unwrap();
+        assert!(is_fresh(&path, MAX_AGE));
+        assert!(!is_fresh(&path, Duration::ZERO));
+        fs::remove_file(&path).unwrap();
+        assert!(!is_fresh(&path, MAX_AGE));
+    }
+}
@@ -0,0 +1,210 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::AocError;
+use crate::solver::Part;
+use crate::submit::Verdict;
+
+const HEADER: &str = "# year\tday\tpart\tanswer\tverdict\tsubmitted_at";
+
+/// One submitted answer and what the site said about it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix timestamp of the submission.
+    pub submitted_at: u64,
+}
+
+/// What the ledger already knows about an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    /// Nothing recorded that says whether it's right.
+    Unknown,
+    /// Same as the recorded correct answer.
+    Correct,
+    /// A different answer is recorded as correct.
+    Regression { expected: String },
+    /// This answer, or one it is bounded by, was rejected before.
+    KnownWrong(Verdict),
+}
+
+/// Append-only record of every submitted answer, kept as a tab-separated file.
+pub struct Ledger {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+// answers.tsv at the workspace root, unless AOC_LEDGER points somewhere else.
+pub fn default_path() -> PathBuf {
+    match std::env::var_os("AOC_LEDGER") {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.tsv"),
+    }
+}
+
+fn verdict_to_str(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Correct => "correct".to_string(),
+        Verdict::TooHigh => "too-high".to_string(),
+        Verdict::TooLow => "too-low".to_string(),
+        Verdict::Wrong => "wrong".to_string(),
+        Verdict::RateLimited { wait } => format!("rate-limited:{}", wait.as_secs()),
+        Verdict::AlreadySolved => "already-solved".to_string(),
+    }
+}
+
+fn verdict_from_str(s: &str) -> Option<Verdict> {
+    match s {
+        "correct" => Some(Verdict::Correct),
+        "too-high" => Some(Verdict::TooHigh),
+        "too-low" => Some(Verdict::TooLow),
+        "wrong" => Some(Verdict::Wrong),
+        "already-solved" => Some(Verdict::AlreadySolved),
+        _ => {
+            let secs = s.strip_prefix("rate-limited:")?.parse().ok()?;
+            Some(Verdict::RateLimited { wait: Duration::from_secs(secs) })
+        }
+    }
+}
+
+fn parse_record(line: &str) -> Option<Record> {
+    let mut fields = line.split('\t');
+    let record = Record {
+        year: fields.next()?.parse().ok()?,
+        day: fields.next()?.parse().ok()?,
+        part: Part::try_from(fields.next()?.parse::<u8>().ok()?).ok()?,
+        answer: fields.next()?.to_string(),
+        verdict: verdict_from_str(fields.next()?)?,
+        submitted_at: fields.next()?.parse().ok()?,
+    };
+    fields.next().is_none().then_some(record)
+}
+
+impl Ledger {
+    // A missing file is an empty ledger, it's created on the first record.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, AocError> {
+        let path = path.into();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(AocError::io(&path, e)),
+        };
+
+        let mut records = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let record = parse_record(line).ok_or_else(|| AocError::CacheCorrupt {
+                path: path.clone(),
+                reason: format!("unreadable record on line {}", i + 1),
+            })?;
+            records.push(record);
+        }
+
+        Ok(Ledger { path, records })
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: Part, answer: &str, verdict: Verdict) -> Result<(), AocError> {
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let record = Record { year, day, part, answer: answer.to_string(), verdict, submitted_at };
+
+        let new_file = !self.path.exists();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| AocError::io(&self.path, e))?;
+        let mut line = String::new();
+        if new_file {
+            line.push_str(HEADER);
+            line.push('\n');
+        }
+        line.push_str(&format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\n",
+            record.year,
+            record.day,
+            record.part,
+            record.answer,
+            verdict_to_str(&record.verdict),
+            record.submitted_at
+        ));
+        file.write_all(line.as_bytes()).map_err(|e| AocError::io(&self.path, e))?;
+
+        self.records.push(record);
+        Ok(())
+    }
+
+    pub fn correct(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.records
+            .iter()
+            .find(|r| r.year == year && r.day == day && r.part == part && r.verdict == Verdict::Correct)
+            .map(|r| r.answer.as_str())
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &str) -> Check {
+        if let Some(expected) = self.correct(year, day, part) {
+            return if expected == answer {
+                Check::Correct
+            } else {
+                Check::Regression { expected: expected.to_string() }
+            };
+        }
+
+        let value = answer.parse::<i64>().ok();
+        for r in self.records.iter().filter(|r| r.year == year && r.day == day && r.part == part) {
+            let bound = r.answer.parse::<i64>().ok();
+            let rejected = match (&r.verdict, value, bound) {
+                (Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong, _, _) if r.answer == answer => true,
+                (Verdict::TooHigh, Some(value), Some(bound)) => value >= bound,
+                (Verdict::TooLow, Some(value), Some(bound)) => value <= bound,
+                _ => false,
+            };
+            if rejected {
+                return Check::KnownWrong(r.verdict.clone());
+            }
+        }
+        Check::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ledger_checks_and_persists() {
+        let path = std::env::temp_dir().join(format!("aoc2023-ledger-{}.tsv", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut ledger = Ledger::load(&path).unwrap();
+        assert_eq!(ledger.check(2023, 4, Part::Two, "30"), Check::Unknown);
+        ledger.record(2023, 4, Part::Two, "100", Verdict::TooHigh).unwrap();
+        ledger.record(2023, 4, Part::Two, "10", Verdict::TooLow).unwrap();
+
+        assert_eq!(ledger.check(2023, 4, Part::Two, "120"), Check::KnownWrong(Verdict::TooHigh));
+        assert_eq!(ledger.check(2023, 4, Part::Two, "5"), Check::KnownWrong(Verdict::TooLow));
+        assert_eq!(ledger.check(2023, 4, Part::Two, "30"), Check::Unknown);
+
+        ledger.record(2023, 4, Part::Two, "30", Verdict::Correct).unwrap();
+
+        let ledger = Ledger::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(ledger.records().len(), 3);
+        assert_eq!(ledger.check(2023, 4, Part::Two, "30"), Check::Correct);
+        assert_eq!(ledger.check(2023, 4, Part::Two, "31"), Check::Regression { expected: "30".to_string() });
+        assert_eq!(ledger.check(2023, 4, Part::One, "30"), Check::Unknown);
+    }
+}
@@ -1,29 +1,239 @@
-use reqwest;
-use std::error::Error;
 use std::fs::{self, File};
-use std::io::Write;
+use std::io::{self, Read, Write};
 use std::path::Path;
+use std::time::Duration;
 
-// Asynchronous function to fetch data from a URL.
-pub async fn fetch_url(url: &str, cookie: String) -> Result<String, reqwest::Error> {
-    let client = reqwest::Client::new();
-    client.get(url).header("Cookie", cookie).send().await?.text().await
+pub mod auth;
+pub mod bench;
+pub mod cache;
+pub mod client;
+pub mod config;
+pub mod error;
+pub mod example;
+pub mod grid;
+mod html;
+pub mod leaderboard;
+pub mod ledger;
+pub mod logging;
+pub mod parse;
+pub mod profile;
+pub mod report;
+pub mod session;
+pub mod solver;
+pub mod submit;
+pub mod unlock;
+
+pub use client::Client;
+pub use error::AocError;
+pub use ledger::Ledger;
+pub use profile::Profile;
+pub use session::SessionCookie;
+pub use solver::{Part, Registry, Solver};
+pub use submit::{submit_answer, Verdict};
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2023;
+
+// Puzzle input endpoint for the given day.
+pub fn input_url(year: u16, day: u8) -> String {
+    format!("{}/{}/day/{}/input", AOC_URL, year, day)
+}
+
+// Asynchronous function to fetch data from a URL, through the shared polite client.
+pub async fn fetch_url(url: &str, cookie: Option<&SessionCookie>) -> Result<String, AocError> {
+    fetch(url, cookie).await.map(|(_, body)| body)
+}
+
+// Like fetch_url, along with the status the body came with.
+async fn fetch(url: &str, cookie: Option<&SessionCookie>) -> Result<(u16, String), AocError> {
+    let response = Client::shared().get(url, cookie).await?;
+
+    let status = response.status();
+    let retry_after = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok()?.parse().ok())
+        .map(Duration::from_secs);
+    let body = response.text().await?;
+
+    if !status.is_success() {
+        return Err(error::classify_response(url, status.as_u16(), retry_after, &body));
+    }
+    Ok((status.as_u16(), body))
+}
+
+// Value of `--flag <value>` or `--flag=<value>` in `args`.
+pub(crate) fn arg_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter().enumerate().find_map(|(i, arg)| match arg.strip_prefix(flag) {
+        Some("") => args.get(i + 1).cloned(),
+        Some(rest) => rest.strip_prefix('=').map(str::to_string),
+        None => None,
+    })
 }
 
-// Function to save data to a file.
-pub fn save_to_file(filename: &str, data: &str) -> Result<(), Box<dyn Error>> {
-    let mut file = File::create(filename)?;
-    file.write_all(data.as_bytes())?;
+// Where `--input <path>` (or a bare `-`) on the command line says to read the
+// input from, if it says anything.
+pub fn input_arg(args: &[String]) -> Option<String> {
+    arg_value(args, "--input").or_else(|| args.iter().skip(1).any(|arg| arg == "-").then(|| "-".to_string()))
+}
+
+// Reads a file, or stdin for `-`.
+pub fn read_input(source: &str) -> Result<String, AocError> {
+    if source == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map_err(|e| AocError::io("<stdin>", e))?;
+        return Ok(input);
+    }
+    fs::read_to_string(source).map_err(|e| AocError::io(source, e))
+}
+
+// Input of a day binary: what `--input` names, or else the input of `profile`
+// from the cache, fetched when it isn't cached yet.
+pub async fn get_input(year: u16, day: u8, profile: &Profile) -> Result<String, AocError> {
+    let args: Vec<String> = std::env::args().collect();
+    match input_arg(&args) {
+        Some(source) => read_input(&source),
+        None => get_data(year, day, profile).await,
+    }
+}
+
+// Function to save data to a file. The data goes to a temporary file next to it
+// first, which replaces `filename` once it's on disk, so an interrupted run
+// leaves either the old file or the new one but never half of one. Missing
+// directories are created.
+pub fn save_to_file(filename: &str, data: &str) -> Result<(), AocError> {
+    let path = Path::new(filename);
+    let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    fs::create_dir_all(dir).map_err(|e| AocError::io(dir, e))?;
+
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let temp = dir.join(format!(".{}.{}.tmp", name, std::process::id()));
+    let written = File::create(&temp)
+        .and_then(|mut file| {
+            file.write_all(data.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&temp, path));
+    if let Err(e) = written {
+        let _ = fs::remove_file(&temp);
+        return Err(AocError::io(filename, e));
+    }
+    // Make the rename itself durable. Not every platform can open a directory for
+    // this, and the data is already safe by now, so failing here is fine.
+    if let Ok(dir) = File::open(dir) {
+        let _ = dir.sync_all();
+    }
     Ok(())
 }
 
+// Input of any year's puzzle for `profile`, cached under the cache root. A 2023
+// input still sitting in its day crate is moved into the cache rather than fetched.
+pub async fn get_data(year: u16, day: u8, profile: &Profile) -> Result<String, AocError> {
+    let path = profile.input_path(year, day);
+    if !path.exists() {
+        if let Some(legacy) = cache::legacy_input_path(year, day, profile).filter(|p| p.exists()) {
+            cache::adopt(&legacy, &path)?;
+        }
+    }
+    get_data_at(&input_url(year, day), profile.cookie(), &path.to_string_lossy()).await
+}
+
 // Function to read data from a file or fetch from a URL if the file does not exist.
-pub async fn get_data(url: &str, cookie: String, local_path: &str) -> Result<String, Box<dyn Error>> {
+// What was fetched is described in a metadata file next to it, whose checksum the
+// cache file has to match. Error pages are never written to the cache, and a
+// cache file holding one from an earlier run, or not matching its checksum, is
+// deleted and fetched again. Puzzles that aren't unlocked yet are refused before
+// any request is made, and so is fetching without a `cookie`.
+pub async fn get_data_at(url: &str, cookie: Option<&SessionCookie>, local_path: &str) -> Result<String, AocError> {
     if Path::new(local_path).exists() {
-        Ok(fs::read_to_string(local_path)?)
-    } else {
-        let fetched_data = fetch_url(url, cookie).await?;
-        save_to_file(local_path, &fetched_data)?;
-        Ok(fetched_data)
+        let cached = fs::read_to_string(local_path).map_err(|e| match e.kind() {
+            io::ErrorKind::InvalidData => AocError::CacheCorrupt {
+                path: local_path.into(),
+                reason: "not valid UTF-8".to_string(),
+            },
+            _ => AocError::io(local_path, e),
+        })?;
+
+        let damage = match cache::Meta::load(Path::new(local_path))? {
+            Some(meta) if meta.checksum != cache::checksum(&cached) => Some("doesn't match its checksum".to_string()),
+            _ => error::input_error_page(url, &cached).map(|e| format!("holds an error page ({})", e)),
+        };
+        match damage {
+            None => return Ok(cached),
+            Some(damage) => {
+                tracing::info!("{} {}, evicting", local_path, damage);
+                fs::remove_file(local_path).map_err(|e| AocError::io(local_path, e))?;
+            }
+        }
+    }
+
+    if let Some((year, day)) = unlock::puzzle_of_url(url) {
+        unlock::ensure_unlocked(year, day)?;
+    }
+    let cookie = cookie.ok_or(AocError::NotLoggedIn)?;
+    let (status, fetched_data) = fetch(url, Some(cookie)).await.map_err(AocError::expired)?;
+    if let Some(e) = error::input_error_page(url, &fetched_data) {
+        return Err(e.expired());
+    }
+    // Metadata goes first: a cache file cut off before it was written is missing,
+    // not trusted.
+    cache::Meta::new(status, &fetched_data).save(Path::new(local_path))?;
+    save_to_file(local_path, &fetched_data)?;
+    Ok(fetched_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_data_evicts_poisoned_cache() {
+        let path = std::env::temp_dir().join(format!("aoc2023-poisoned-{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+        save_to_file(path, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n").unwrap();
+
+        // Nothing listens on the discard port, so the refetch fails without touching the network.
+        let result = get_data_at("http://127.0.0.1:9/2023/day/1/input", SessionCookie::new("abc").as_ref(), path).await;
+
+        assert!(matches!(result, Err(AocError::Http(_))));
+        assert!(!Path::new(path).exists());
+    }
+
+    #[tokio::test]
+    async fn test_get_data_evicts_damaged_cache() {
+        let path = std::env::temp_dir().join(format!("aoc2023-damaged-{}.txt", std::process::id()));
+        cache::Meta::new(200, "1abc2\npqr3stu8vwx\n").save(&path).unwrap();
+        let path = path.to_str().unwrap();
+        save_to_file(path, "1abc2\npq").unwrap();
+
+        let result = get_data_at("http://127.0.0.1:9/2023/day/1/input", SessionCookie::new("abc").as_ref(), path).await;
+
+        assert!(matches!(result, Err(AocError::Http(_))));
+        assert!(!Path::new(path).exists());
+        fs::remove_file(cache::meta_path(Path::new(path))).unwrap();
+    }
+
+    #[test]
+    fn test_save_to_file_creates_dirs_and_replaces() {
+        let dir = std::env::temp_dir().join(format!("aoc2023-save-{}", std::process::id()));
+        let path = dir.join("output").join("output.txt");
+        let path_str = path.to_str().unwrap();
+
+        save_to_file(path_str, "old").unwrap();
+        save_to_file(path_str, "new").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        // No temporary file is left behind.
+        assert_eq!(fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_input_arg() {
+        let args = |s: &str| s.split(' ').map(str::to_string).collect::<Vec<_>>();
+        assert_eq!(input_arg(&args("day04b --input ../inputs/bob.txt --submit")).as_deref(), Some("../inputs/bob.txt"));
+        assert_eq!(input_arg(&args("day04b --input=-")).as_deref(), Some("-"));
+        assert_eq!(input_arg(&args("day04b -")).as_deref(), Some("-"));
+        assert_eq!(input_arg(&args("day04b --profile=bob")), None);
+        assert_eq!(arg_value(&args("day04b --profile bob"), "--profile").as_deref(), Some("bob"));
     }
 }
@@ -0,0 +1,72 @@
+use std::env;
+use std::io::IsTerminal;
+
+use tracing_subscriber::EnvFilter;
+
+/// Per-module filters, in `tracing` directive syntax, e.g.
+/// `AOC_LOG=aoc03a=trace,aoc2023::client=debug`. Overrides `-v`.
+pub const ENV: &str = "AOC_LOG";
+
+// Level for everything at `verbose` times `-v`: warnings only without any, then
+// info, debug and trace.
+fn level(verbose: u8) -> &'static str {
+    match verbose {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    }
+}
+
+// How many times `-v` (or `--verbose`) is on the command line, `-vv` counting twice.
+pub fn verbosity(args: &[String]) -> u8 {
+    let count = args
+        .iter()
+        .skip(1)
+        .map(|arg| match arg.strip_prefix('-') {
+            Some("-verbose") => 1,
+            Some(flags) if !flags.is_empty() && flags.chars().all(|c| c == 'v') => flags.len(),
+            _ => 0,
+        })
+        .sum::<usize>();
+    count.min(u8::MAX as usize) as u8
+}
+
+// Sends log events to stderr, filtered by AOC_LOG when set and by `verbose`
+// otherwise. Works the same in release builds. Only the first call does anything.
+pub fn init(verbose: u8) {
+    let filter = match env::var(ENV) {
+        Ok(directives) if !directives.trim().is_empty() => EnvFilter::try_new(&directives).unwrap_or_else(|e| {
+            eprintln!("warning: ignoring {}: {}", ENV, e);
+            EnvFilter::new(level(verbose))
+        }),
+        _ => EnvFilter::new(level(verbose)),
+    };
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .try_init();
+}
+
+// What a day binary does, with the `-v` flags on its own command line.
+pub fn init_from_args() {
+    let args: Vec<String> = env::args().collect();
+    init(verbosity(&args));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity() {
+        let args = |s: &str| s.split(' ').map(str::to_string).collect::<Vec<_>>();
+        assert_eq!(verbosity(&args("day03a")), 0);
+        assert_eq!(verbosity(&args("day03a -v --submit")), 1);
+        assert_eq!(verbosity(&args("day03a -vv -v")), 3);
+        assert_eq!(verbosity(&args("day03a --verbose --input -")), 1);
+        assert_eq!(level(2), "debug");
+        assert_eq!(level(9), "trace");
+    }
+}
@@ -0,0 +1,367 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Where parsing a puzzle input went wrong and what it expected there instead.
+/// Displays like a compiler error, with a caret under the offending text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Puzzle day, filled in by the registry when a solver's parse fails.
+    pub day: Option<u8>,
+    /// 1-based, like an editor shows it.
+    pub line: usize,
+    /// 1-based, in characters.
+    pub column: usize,
+    /// The whole line the error is on.
+    pub source: String,
+    /// What was found at the column, empty at the end of a line.
+    pub found: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn on_day(self, day: u8) -> Self {
+        ParseError { day: Some(day), ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(f, "line {}, column {}: expected {}", self.line, self.column, self.expected)?;
+        match self.found.as_str() {
+            "" => writeln!(f, ", found the end of the line")?,
+            found => writeln!(f, ", found `{}`", found)?,
+        }
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{}", format!("{} | {}", self.line, self.source).trim_end())?;
+        let carets = "^".repeat(self.found.chars().count().max(1));
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), carets)
+    }
+}
+
+impl Error for ParseError {}
+
+static STRICT: AtomicBool = AtomicBool::new(true);
+
+// Makes the day parsers reject empty lines, trailing garbage and sections they
+// don't know instead of skipping or ignoring them.
+pub fn set_strict(strict: bool) {
+    STRICT.store(strict, Ordering::Relaxed);
+}
+
+pub fn is_strict() -> bool {
+    STRICT.load(Ordering::Relaxed)
+}
+
+// The mode `--strict` and `--lenient` ask for, the same for the runner and the
+// day binaries: strict unless only `--lenient` is given.
+pub fn strict_mode(strict: bool, lenient: bool) -> bool {
+    strict || !lenient
+}
+
+// Whether `args` hold `--strict` and `--lenient`, not counting the value of a flag
+// that takes one, as in `--input --strict`.
+pub fn mode_flags(args: &[String]) -> (bool, bool) {
+    let (mut strict, mut lenient) = (false, false);
+    let mut args = args.iter().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--strict" => strict = true,
+            "--lenient" => lenient = true,
+            "--input" | "--profile" => {
+                args.next();
+            }
+            _ => {}
+        }
+    }
+    (strict, lenient)
+}
+
+// What a day binary does at startup; the runner gets the flags from clap.
+pub fn init_from_args() {
+    let args: Vec<String> = env::args().collect();
+    let (strict, lenient) = mode_flags(&args);
+    set_strict(strict_mode(strict, lenient));
+}
+
+/// A piece of the input that remembers where in the input it is, so errors about
+/// it can say so. Derefs to the `&str` it covers.
+#[derive(Clone, Copy, Debug)]
+pub struct Span<'a> {
+    input: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Span { input, start: 0, end: input.len() }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        &self.input[self.start..self.end]
+    }
+
+    // The span of `part`, which has to be a slice of this span's text.
+    fn of(&self, part: &'a str) -> Span<'a> {
+        let start = part.as_ptr() as usize - self.input.as_ptr() as usize;
+        debug_assert!(start >= self.start && start + part.len() <= self.end, "not a part of this span");
+        Span { input: self.input, start, end: start + part.len() }
+    }
+
+    // Line and column of the start of the span.
+    pub fn position(&self) -> (usize, usize) {
+        let before = &self.input[..self.start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+    }
+
+    // An error about this span, pointing at the part of it on its first line.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let (line, column) = self.position();
+        let line_start = self.input[..self.start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.input[self.start..].find('\n').map_or(self.input.len(), |i| self.start + i);
+        let source = self.input[line_start..line_end].trim_end_matches('\r');
+        let found = self.input[self.start..self.end.min(line_end)].trim_end_matches('\r');
+        ParseError {
+            day: None,
+            line,
+            column,
+            source: source.to_string(),
+            found: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    // The part of the span from byte `start` to `end` of its text.
+    pub fn slice(&self, start: usize, end: usize) -> Span<'a> {
+        self.of(&self.as_str()[start..end])
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        self.of(self.as_str().trim())
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> + 'a {
+        let span = *self;
+        self.as_str().lines().map(move |line| span.of(line))
+    }
+
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Span<'a>> + 'a {
+        let span = *self;
+        self.as_str().split(separator).map(move |part| span.of(part))
+    }
+
+    // Around the first `separator`, which has to be there.
+    pub fn split_once(&self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let text = self.as_str();
+        match text.split_once(separator) {
+            Some((before, after)) => Ok((self.of(before), self.of(after))),
+            None => Err(self.of(&text[text.len()..]).error(format!("`{}`", separator))),
+        }
+    }
+
+    // Whitespace separated words.
+    pub fn words(&self) -> impl Iterator<Item = Span<'a>> + 'a {
+        let span = *self;
+        self.as_str().split_whitespace().map(move |word| span.of(word))
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        match self.as_str().strip_prefix(prefix) {
+            Some(rest) => Ok(self.of(rest)),
+            None => Err(self.error(format!("`{}`", prefix))),
+        }
+    }
+}
+
+impl std::ops::Deref for Span<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+fn number<T: FromStr>(span: Span, signed: bool) -> Result<T, ParseError> {
+    let span = span.trim();
+    let text = span.as_str();
+    let digits = match text.strip_prefix(['-', '+']) {
+        Some(digits) if signed => digits,
+        _ => text,
+    };
+    if digits.is_empty() {
+        return Err(span.error(if signed { "a signed integer" } else { "an integer" }));
+    }
+    if let Some((bad, c)) = digits.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(span.of(&digits[bad..bad + c.len_utf8()]).error("a digit"));
+    }
+    text.parse().map_err(|_| span.error(format!("an integer that fits in {}", std::any::type_name::<T>())))
+}
+
+// Digits only, surrounding whitespace aside.
+pub fn integer<T: FromStr>(span: Span) -> Result<T, ParseError> {
+    number(span, false)
+}
+
+// Digits with an optional leading `-` or `+`.
+pub fn signed<T: FromStr>(span: Span) -> Result<T, ParseError> {
+    number(span, true)
+}
+
+// Whitespace separated items, each parsed by `item`.
+pub fn list<'a, T>(span: Span<'a>, item: impl Fn(Span<'a>) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    span.words().map(item).collect()
+}
+
+pub fn each_line<'a, T>(span: Span<'a>, item: impl Fn(Span<'a>) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    each_line_in(span, is_strict(), item)
+}
+
+// One item per line, each parsed by `item`. Strict, every line has to be one;
+// lenient, empty lines and lines that don't parse are skipped.
+pub fn each_line_in<'a, T>(
+    span: Span<'a>,
+    strict: bool,
+    item: impl Fn(Span<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let mut items = Vec::new();
+    for line in span.lines() {
+        let parsed = match line.trim().is_empty() {
+            true => Err(line.error("a line that isn't empty")),
+            false => item(line),
+        };
+        match parsed {
+            Ok(parsed) => items.push(parsed),
+            Err(e) if !strict => tracing::warn!("skipping line {}, expected {}", e.line, e.expected),
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(items)
+}
+
+pub fn integers<T: FromStr>(span: Span) -> Result<Vec<T>, ParseError> {
+    list(span, integer)
+}
+
+pub fn signed_integers<T: FromStr>(span: Span) -> Result<Vec<T>, ParseError> {
+    list(span, signed)
+}
+
+// What follows `label` at the start of the span, trimmed, e.g. the `3` of `Game 3`.
+pub fn labelled<'a>(span: Span<'a>, label: &str) -> Result<Span<'a>, ParseError> {
+    Ok(span.trim().strip_prefix(label)?.trim())
+}
+
+// Title and body of a `title: body` section, the body either on the same line
+// or on the lines after it. Both trimmed.
+pub fn section(span: Span) -> Result<(Span, Span), ParseError> {
+    let (title, body) = span.trim().split_once(":")?;
+    Ok((title.trim(), body.trim()))
+}
+
+// Key/value pairs, the pairs separated by `separator` and each key from its
+// value by the first `between`, e.g. `3 blue, 4 red` with `,` and ` `.
+pub fn record<'a>(span: Span<'a>, separator: &'a str, between: &str) -> Result<Vec<(Span<'a>, Span<'a>)>, ParseError> {
+    span.split(separator)
+        .map(|pair| {
+            let (key, value) = pair.trim().split_once(between)?;
+            Ok((key.trim(), value.trim()))
+        })
+        .collect()
+}
+
+// Runs of lines separated by blank ones, blank meaning nothing but whitespace.
+pub fn blocks<'a>(span: Span<'a>) -> impl Iterator<Item = Span<'a>> + 'a {
+    let mut lines = span.lines().peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|line| line.trim().is_empty()).is_some() {}
+        let first = lines.next()?;
+        let mut last = first;
+        while let Some(line) = lines.next_if(|line| !line.trim().is_empty()) {
+            last = line;
+        }
+        Some(Span { input: first.input, start: first.start, end: last.end })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let input = Span::new("12 -7 +3 x4");
+        let words: Vec<Span> = input.words().collect();
+        assert_eq!(integer::<u32>(words[0]), Ok(12));
+        assert_eq!(signed::<i32>(words[1]), Ok(-7));
+        assert_eq!(signed::<i32>(words[2]), Ok(3));
+        let err = integer::<u32>(words[1]).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str(), err.expected.as_str()), (1, 4, "-", "a digit"));
+        assert_eq!(integer::<u32>(words[3]).unwrap_err().column, 10);
+        assert_eq!(integer::<u8>(Span::new("300")).unwrap_err().expected, "an integer that fits in u8");
+        assert_eq!(integers::<u64>(Span::new(" 1  2 3\n")), Ok(vec![1, 2, 3]));
+        assert_eq!(signed_integers::<i64>(Span::new("-1 2")), Ok(vec![-1, 2]));
+    }
+
+    #[test]
+    fn test_structure() {
+        let input = Span::new("Game 3: 3 blue, 4 red\nGame x: 1 red");
+        let lines: Vec<Span> = input.lines().collect();
+
+        let (head, body) = lines[0].split_once(":").unwrap();
+        assert_eq!(integer::<u32>(labelled(head, "Game").unwrap()), Ok(3));
+        let pairs: Vec<(&str, &str)> = record(body, ",", " ").unwrap().iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        assert_eq!(pairs, [("3", "blue"), ("4", "red")]);
+
+        let (head, _) = lines[1].split_once(":").unwrap();
+        let err = integer::<u32>(labelled(head, "Game").unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 6: expected a digit, found `x`\n2 | Game x: 1 red\n  |      ^");
+        assert_eq!(labelled(head, "Card").unwrap_err().expected, "`Card`");
+        let err = lines[1].split_once("|").unwrap_err().on_day(2);
+        assert_eq!(err.to_string(), "day 2, line 2, column 14: expected `|`, found the end of the line\n2 | Game x: 1 red\n  |              ^");
+    }
+
+    #[test]
+    fn test_mode_flags() {
+        let mode = |s: &str| {
+            let args: Vec<String> = s.split(' ').map(str::to_string).collect();
+            let (strict, lenient) = mode_flags(&args);
+            strict_mode(strict, lenient)
+        };
+        assert!(mode("aoc02a"));
+        assert!(mode("aoc02a --input bad.txt --strict"));
+        assert!(!mode("aoc02a --lenient"));
+        assert!(!mode("aoc02a --profile alice --lenient"));
+        assert!(mode("aoc02a --input --lenient"));
+        assert_eq!(mode_flags(&["aoc02a".into(), "--input".into(), "--strict".into()]), (false, false));
+    }
+
+    #[test]
+    fn test_each_line() {
+        let input = Span::new("1\n\n2\nx\n");
+        assert_eq!(each_line_in(input, false, integer::<u32>), Ok(vec![1, 2]));
+        assert_eq!(each_line_in(input, true, integer::<u32>).unwrap_err().line, 2);
+        assert_eq!(each_line_in(Span::new("1\nx"), true, integer::<u32>).unwrap_err().found, "x");
+    }
+
+    #[test]
+    fn test_sections_and_blocks() {
+        let input = Span::new("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n  \n\nlast:\n1 2 3\n");
+        let blocks: Vec<Span> = blocks(input).collect();
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[1].as_str(), "seed-to-soil map:\n50 98 2\n52 50 48");
+
+        let (title, body) = section(blocks[0]).unwrap();
+        assert_eq!((title.as_str(), integers::<u64>(body)), ("seeds", Ok(vec![79, 14])));
+        let (title, body) = section(blocks[1]).unwrap();
+        assert_eq!(title.as_str(), "seed-to-soil map");
+        assert_eq!(body.lines().next().unwrap().position(), (4, 1));
+        assert_eq!(section(blocks[2]).unwrap().1.position(), (9, 1));
+    }
+}
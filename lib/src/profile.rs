@@ -0,0 +1,126 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+use crate::cache;
+use crate::config::{self, Config};
+use crate::error::AocError;
+use crate::session::{self, SessionCookie};
+
+/// Name of the profile used when none is configured. It logs in with the cookie
+/// in the file SESSION_COOKIE_FILE names, or else SESSION_COOKIE.
+pub const DEFAULT: &str = "default";
+
+/// One adventofcode.com account, with its own input caches and answer ledger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Profile {
+    pub name: String,
+    session: Option<SessionCookie>,
+    cache_root: PathBuf,
+}
+
+// Profile names end up in file names, so keep them to something harmless.
+fn valid_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+impl Profile {
+    // Picks `name`, else AOC_PROFILE, else the config's default_profile, else the
+    // default profile. Its session comes from the config, except for an
+    // unconfigured default profile which falls back to the environment.
+    pub fn select(config: &Config, name: Option<&str>) -> Result<Self, AocError> {
+        let name = name
+            .map(str::to_string)
+            .or_else(|| env::var("AOC_PROFILE").ok())
+            .or_else(|| config.default_profile.clone())
+            .unwrap_or_else(|| DEFAULT.to_string());
+        if !valid_name(&name) {
+            return Err(AocError::UnknownProfile { name });
+        }
+
+        let session = match config.profiles.get(&name) {
+            Some(profile) => match (&profile.session, &profile.session_file) {
+                (_, Some(file)) => Some(session::read_session_file(file)?),
+                (Some(session), None) => SessionCookie::new(session),
+                (None, None) => None,
+            },
+            None if name == DEFAULT => match env::var_os("SESSION_COOKIE_FILE") {
+                Some(file) => Some(session::read_session_file(Path::new(&file))?),
+                None => env::var("SESSION_COOKIE").ok().and_then(|s| SessionCookie::new(&s)),
+            },
+            None => return Err(AocError::UnknownProfile { name }),
+        };
+        Ok(Profile { name, session, cache_root: cache::root(config) })
+    }
+
+    // Profile picked with `--profile <name>` on the command line of a day binary.
+    pub fn from_args() -> Result<Self, AocError> {
+        let args: Vec<String> = env::args().collect();
+        let config = Config::load(config::default_path())?;
+        Profile::select(&config, crate::arg_value(&args, "--profile").as_deref())
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.name == DEFAULT
+    }
+
+    // `None` when there is no session to log in with.
+    pub fn cookie(&self) -> Option<&SessionCookie> {
+        self.session.as_ref()
+    }
+
+    // Where this profile's input of `year`/`day` is cached.
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        cache::input_path(&self.cache_root, year, day, &self.name)
+    }
+
+    pub fn leaderboard_path(&self, year: u16, id: u64) -> PathBuf {
+        cache::leaderboard_path(&self.cache_root, year, &self.name, id)
+    }
+
+    // Answers differ per account, so each profile but the default one gets its own
+    // ledger next to answers.tsv, e.g. answers-alice.tsv.
+    pub fn ledger_path(&self) -> PathBuf {
+        let path = crate::ledger::default_path();
+        if self.is_default() {
+            return path;
+        }
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let name = match path.extension() {
+            Some(ext) => format!("{}-{}.{}", stem, self.name, ext.to_string_lossy()),
+            None => format!("{}-{}", stem, self.name),
+        };
+        path.with_file_name(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ProfileConfig;
+
+    #[test]
+    fn test_select_profile() {
+        let mut config = Config::default();
+        let session = |s: &str| ProfileConfig { session: Some(s.to_string()), session_file: None };
+        config.profiles.insert("alice".to_string(), session("53616c"));
+        config.profiles.insert("bob".to_string(), session("session=b0b"));
+
+        let alice = Profile::select(&config, Some("alice")).unwrap();
+        assert_eq!(alice.cookie().unwrap().header(), "session=53616c");
+        assert!(alice.input_path(2023, 4).ends_with("2023/4/alice/input.txt"));
+        assert!(alice.ledger_path().ends_with("answers-alice.tsv"));
+
+        let bob = Profile::select(&config, Some("bob")).unwrap();
+        assert_eq!(bob.cookie().unwrap().header(), "session=b0b");
+
+        assert!(matches!(Profile::select(&config, Some("carol")), Err(AocError::UnknownProfile { .. })));
+        assert!(matches!(Profile::select(&config, Some("../x")), Err(AocError::UnknownProfile { .. })));
+
+        config.cache_dir = Some(PathBuf::from("/var/cache/aoc"));
+        let default = Profile { session: None, ..Profile::select(&config, Some(DEFAULT)).unwrap() };
+        assert_eq!(default.cookie(), None);
+        assert_eq!(default.input_path(2015, 7), cache::root(&config).join("2015/7/default/input.txt"));
+        assert_eq!(cache::root_with(None, &config), Path::new("/var/cache/aoc"));
+        assert_eq!(cache::root_with(Some("/tmp/aoc".into()), &config), Path::new("/tmp/aoc"));
+    }
+}
@@ -0,0 +1,155 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cache;
+use crate::error::AocError;
+use crate::profile::Profile;
+use crate::solver::{Entry, Part, Solver};
+
+/// What one run of a solver produced, written as JSON to
+/// `<dir>/<year>/<day>/<profile>/part<part>.json` by every build.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub profile: String,
+    pub answer: String,
+    /// `sha256:<hex>` of the input, the same as in the input cache's metadata.
+    pub input_checksum: String,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    /// Type name of the solver, e.g. `aoc04b::Day04b`.
+    pub solver: String,
+    pub solver_version: String,
+    /// `git describe --always --dirty` of the workspace, when it is a git checkout.
+    pub commit: Option<String>,
+    /// `debug` or `release`.
+    pub build: String,
+    /// Unix timestamp of when the run finished.
+    pub finished_at: u64,
+}
+
+// reports/ at the workspace root, unless AOC_REPORT_DIR points somewhere else.
+pub fn default_dir() -> PathBuf {
+    match std::env::var_os("AOC_REPORT_DIR") {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../reports"),
+    }
+}
+
+// Looked up once per process, every report of a run shares it.
+fn commit() -> Option<String> {
+    static COMMIT: OnceLock<Option<String>> = OnceLock::new();
+    COMMIT
+        .get_or_init(|| {
+            let output = Command::new("git")
+                .arg("-C")
+                .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+                .args(["describe", "--always", "--dirty"])
+                .output()
+                .ok()?;
+            let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+            (output.status.success() && !commit.is_empty()).then_some(commit)
+        })
+        .clone()
+}
+
+fn nanos(d: Duration) -> u64 {
+    u64::try_from(d.as_nanos()).unwrap_or(u64::MAX)
+}
+
+// Parses and solves `input` with `entry`, timing both.
+pub fn run(entry: &Entry, profile: &Profile, input: &str) -> Result<Report, Box<dyn Error>> {
+    let start = Instant::now();
+    let parsed = entry.parse(input)?;
+    let parsed_at = Instant::now();
+    let answer = entry.solve(parsed.as_ref())?;
+    let solved_at = Instant::now();
+
+    Ok(Report {
+        year: crate::YEAR,
+        day: entry.day,
+        part: entry.part.number(),
+        profile: profile.name.clone(),
+        answer,
+        input_checksum: cache::checksum(input),
+        parse_ns: nanos(parsed_at - start),
+        solve_ns: nanos(solved_at - parsed_at),
+        solver: entry.name.to_string(),
+        solver_version: entry.version.to_string(),
+        commit: commit(),
+        build: if cfg!(debug_assertions) { "debug" } else { "release" }.to_string(),
+        finished_at: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+    })
+}
+
+// What a day binary does: runs its solver for `part` and saves the report.
+pub fn solve<S: Solver>(part: Part, profile: &Profile, input: &str) -> Result<Report, Box<dyn Error>> {
+    let report = run(&Entry::new::<S>(part), profile, input)?;
+    report.save(default_dir())?;
+    Ok(report)
+}
+
+impl Report {
+    pub fn path(&self, dir: impl AsRef<Path>) -> PathBuf {
+        dir.as_ref()
+            .join(self.year.to_string())
+            .join(self.day.to_string())
+            .join(&self.profile)
+            .join(format!("part{}.json", self.part))
+    }
+
+    // Replaces the report of the previous run of the same part.
+    pub fn save(&self, dir: impl AsRef<Path>) -> Result<PathBuf, AocError> {
+        let path = self.path(dir);
+        let json = serde_json::to_string_pretty(self).expect("report serializes");
+        crate::save_to_file(&path.to_string_lossy(), &format!("{}\n", json))?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    struct Count;
+
+    impl Solver for Count {
+        const DAY: u8 = 7;
+        const VERSION: &'static str = "1.2.3";
+        type Input = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+            Ok(input.lines().count())
+        }
+
+        fn part2(input: &Self::Input) -> Result<String, Box<dyn Error>> {
+            Ok(input.to_string())
+        }
+    }
+
+    #[test]
+    fn test_run_and_save() {
+        let profile = Profile::select(&Config::default(), Some("default")).unwrap();
+        let report = run(&Entry::new::<Count>(Part::Two), &profile, "a\nb\n").unwrap();
+        assert_eq!((report.day, report.part, report.answer.as_str()), (7, 2, "2"));
+        assert_eq!(report.input_checksum, cache::checksum("a\nb\n"));
+        assert_eq!(report.solver_version, "1.2.3");
+        assert!(report.solver.ends_with("Count"));
+        assert!(run(&Entry::new::<Count>(Part::One), &profile, "").is_err());
+
+        let dir = std::env::temp_dir().join(format!("aoc2023-reports-{}", std::process::id()));
+        let path = report.save(&dir).unwrap();
+        assert!(path.ends_with("2023/7/default/part2.json"));
+        let saved: Report = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved, report);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::error::AocError;
+
+// Sessions are 128 hex digits, nothing else we keep in the repo (sha256 sums in
+// Cargo.lock, git hashes) comes close to this many in a row.
+const MIN_TOKEN_LEN: usize = 96;
+
+/// Value of the adventofcode.com `session` cookie. It only leaves this type as a
+/// request header, `Debug` never shows it and there is no `Display`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SessionCookie(String);
+
+impl SessionCookie {
+    // Accepts the bare token or `session=<token>`, `None` for an empty one.
+    pub fn new(value: &str) -> Option<Self> {
+        let token = value.trim();
+        let token = token.strip_prefix("session=").unwrap_or(token).trim();
+        (!token.is_empty()).then(|| SessionCookie(token.to_string()))
+    }
+
+    // Value for the Cookie header.
+    pub fn header(&self) -> String {
+        format!("session={}", self.0)
+    }
+}
+
+impl fmt::Debug for SessionCookie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SessionCookie(<redacted>)")
+    }
+}
+
+// Refuses files other users can read: whoever has the cookie has the account.
+#[cfg(unix)]
+pub fn check_permissions(path: &Path) -> Result<(), AocError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = fs::metadata(path).map_err(|e| AocError::io(path, e))?.permissions().mode();
+    if mode & 0o004 != 0 {
+        return Err(AocError::InsecureSecret { path: path.to_path_buf(), mode: mode & 0o777 });
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn check_permissions(_path: &Path) -> Result<(), AocError> {
+    Ok(())
+}
+
+// Reads a cookie from a file holding nothing else, e.g. `~/.config/aoc/session`.
+pub fn read_session_file(path: &Path) -> Result<SessionCookie, AocError> {
+    check_permissions(path)?;
+    let text = fs::read_to_string(path).map_err(|e| AocError::io(path, e))?;
+    SessionCookie::new(&text).ok_or_else(|| AocError::Config {
+        path: path.to_path_buf(),
+        reason: "the session file is empty".to_string(),
+    })
+}
+
+// 1-based line and column of everything in `text` that looks like a session token.
+pub fn find_session_tokens(text: &str) -> Vec<(usize, usize)> {
+    let mut found = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let mut run_start = None;
+        for (col, c) in line.char_indices().chain([(line.len(), ' ')]) {
+            match (c.is_ascii_hexdigit(), run_start) {
+                (true, None) => run_start = Some(col),
+                (false, Some(start)) => {
+                    if col - start >= MIN_TOKEN_LEN {
+                        found.push((i + 1, start + 1));
+                    }
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_cookie_is_redacted() {
+        let cookie = SessionCookie::new("session=53616c7465645f5f").unwrap();
+        assert_eq!(cookie.header(), "session=53616c7465645f5f");
+        assert_eq!(SessionCookie::new("53616c7465645f5f"), Some(cookie.clone()));
+        assert!(!format!("{:?}", Some(&cookie)).contains("5361"));
+        assert_eq!(SessionCookie::new(" \n"), None);
+    }
+
+    #[test]
+    fn test_find_session_tokens() {
+        let token = "ab12".repeat(32);
+        let text = format!("SESSION_COOKIE=\"session={}\";\nchecksum = \"{}\"\n", token, "f".repeat(64));
+        assert_eq!(find_session_tokens(&text), vec![(1, 25)]);
+        assert!(find_session_tokens("seeds: 79 14 55 13\n").is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_read_session_file_checks_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join(format!("aoc2023-session-{}", std::process::id()));
+        fs::write(&path, "session=abc\n").unwrap();
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        assert!(matches!(read_session_file(&path), Err(AocError::InsecureSecret { mode: 0o644, .. })));
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+        let cookie = read_session_file(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(cookie.unwrap().header(), "session=abc");
+    }
+}
@@ -0,0 +1,212 @@
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+
+use crate::parse::ParseError;
+
+/// One of the two halves of a day's puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("part must be 1 or 2, got {}", value)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+// Returned by the default part implementations, so a day crate only has to
+// implement the half of the puzzle it actually solves.
+#[derive(Debug)]
+pub struct Unsolved {
+    pub day: u8,
+    pub part: Part,
+}
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} part {} is not solved yet", self.day, self.part)
+    }
+}
+
+impl Error for Unsolved {}
+
+/// A puzzle solution: parse the input once, then answer either part from it.
+pub trait Solver {
+    /// Puzzle day, 1 to 25.
+    const DAY: u8;
+
+    /// Version of the crate the solver lives in, recorded in run reports.
+    const VERSION: &'static str;
+
+    /// Parsed puzzle input shared by both parts.
+    type Input: 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>>;
+
+    fn part1(_input: &Self::Input) -> Result<String, Box<dyn Error>> {
+        Err(Box::new(Unsolved { day: Self::DAY, part: Part::One }))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<String, Box<dyn Error>> {
+        Err(Box::new(Unsolved { day: Self::DAY, part: Part::Two }))
+    }
+}
+
+type ParseFn = fn(&str) -> Result<Box<dyn Any>, Box<dyn Error>>;
+type SolveFn = fn(&dyn Any, Part) -> Result<String, Box<dyn Error>>;
+
+/// Type-erased handle to the solver registered for one day and part.
+pub struct Entry {
+    pub day: u8,
+    pub part: Part,
+    pub name: &'static str,
+    pub version: &'static str,
+    parse: ParseFn,
+    solve: SolveFn,
+}
+
+impl Entry {
+    pub fn new<S: Solver>(part: Part) -> Self {
+        Entry {
+            day: S::DAY,
+            part,
+            name: std::any::type_name::<S>(),
+            version: S::VERSION,
+            parse: parse_erased::<S>,
+            solve: solve_erased::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>, Box<dyn Error>> {
+        (self.parse)(input)
+    }
+
+    // `parsed` must come from `Entry::parse` of this same entry.
+    pub fn solve(&self, parsed: &dyn Any) -> Result<String, Box<dyn Error>> {
+        (self.solve)(parsed, self.part)
+    }
+
+    pub fn run(&self, input: &str) -> Result<String, Box<dyn Error>> {
+        let parsed = self.parse(input)?;
+        self.solve(parsed.as_ref())
+    }
+}
+
+// Parse errors of a solver don't know its day, the registry does.
+fn parse_erased<S: Solver>(input: &str) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    match S::parse(input) {
+        Ok(parsed) => Ok(Box::new(parsed)),
+        Err(e) => match e.downcast::<ParseError>() {
+            Ok(e) => Err(Box::new(e.on_day(S::DAY))),
+            Err(e) => Err(e),
+        },
+    }
+}
+
+fn solve_erased<S: Solver>(parsed: &dyn Any, part: Part) -> Result<String, Box<dyn Error>> {
+    let input = parsed
+        .downcast_ref::<S::Input>()
+        .expect("parsed input handed to a different solver");
+    match part {
+        Part::One => S::part1(input),
+        Part::Two => S::part2(input),
+    }
+}
+
+/// Maps (day, part) to the solver that answers it.
+#[derive(Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Days are split over an "a" and a "b" crate, so registration says which
+    // part of the puzzle the given solver answers.
+    pub fn register<S: Solver>(&mut self, part: Part) -> &mut Self {
+        self.entries.retain(|e| !(e.day == S::DAY && e.part == part));
+        self.entries.push(Entry::new::<S>(part));
+        self.entries.sort_by_key(|e| (e.day, e.part));
+        self
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.day == day && e.part == part)
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self.entries.iter().map(|e| e.day).collect();
+        days.dedup();
+        days
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{integer, Span};
+
+    struct Sum;
+
+    impl Solver for Sum {
+        const DAY: u8 = 1;
+        const VERSION: &'static str = "0.1.0";
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input, Box<dyn Error>> {
+            Ok(Span::new(input).lines().map(integer).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<String, Box<dyn Error>> {
+            Ok(input.iter().sum::<u32>().to_string())
+        }
+    }
+
+    #[test]
+    fn test_registry_dispatch() {
+        let mut registry = Registry::new();
+        registry.register::<Sum>(Part::One).register::<Sum>(Part::Two);
+
+        assert_eq!(registry.days(), vec![1]);
+        assert_eq!(registry.get(1, Part::One).unwrap().run("1\n2\n3").unwrap(), "6");
+        let err = registry.get(1, Part::Two).unwrap().run("1").unwrap_err();
+        assert!(err.downcast_ref::<Unsolved>().is_some());
+        assert!(registry.get(2, Part::One).is_none());
+
+        let err = registry.get(1, Part::One).unwrap().run("1\nx").unwrap_err();
+        assert_eq!(err.downcast_ref::<ParseError>().unwrap().day, Some(1));
+    }
+}
@@ -0,0 +1,278 @@
+use std::env;
+use std::fmt;
+use std::time::Duration;
+
+use crate::client::Client;
+use crate::error::{self, AocError};
+use crate::html;
+use crate::ledger::{Check, Ledger};
+use crate::profile::Profile;
+use crate::session::SessionCookie;
+use crate::solver::Part;
+
+/// What adventofcode.com said about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited { wait: Duration },
+    AlreadySolved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::TooHigh => write!(f, "That's not the right answer, your answer is too high."),
+            Verdict::TooLow => write!(f, "That's not the right answer, your answer is too low."),
+            Verdict::Wrong => write!(f, "That's not the right answer."),
+            Verdict::RateLimited { wait } => {
+                write!(f, "You gave an answer too recently, wait {}s before trying again.", wait.as_secs())
+            }
+            Verdict::AlreadySolved => write!(f, "This part is already solved."),
+        }
+    }
+}
+
+// Text of the <article> of the answer page, which is where the site puts its verdict.
+fn article_text(html: &str) -> String {
+    let article = html::between(html, "<article", "</article>").into_iter().next().unwrap_or(html);
+    html::strip_tags(article).split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// Reads the "You have 1m 30s left to wait" part of a rate limit message.
+fn parse_wait(text: &str) -> Duration {
+    let before = text.split("left to wait").next().unwrap_or("");
+    let secs = before
+        .split_whitespace()
+        .rev()
+        .take_while(|word| *word != "have")
+        .filter_map(|word| {
+            let (number, unit) = word.split_at(word.find(|c: char| !c.is_ascii_digit())?);
+            let number = number.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum();
+    Duration::from_secs(secs)
+}
+
+pub fn parse_verdict(html: &str) -> Option<Verdict> {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Some(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Some(Verdict::TooHigh)
+        } else if text.contains("too low") {
+            Some(Verdict::TooLow)
+        } else {
+            Some(Verdict::Wrong)
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Some(Verdict::RateLimited { wait: parse_wait(&text) })
+    } else if text.contains("Did you already complete it") {
+        Some(Verdict::AlreadySolved)
+    } else {
+        None
+    }
+}
+
+// The answer the puzzle page shows for `level` once the account has solved it,
+// "Your puzzle answer was <code>54331</code>." under each solved part.
+pub fn parse_solved_answer(html: &str, level: Part) -> Option<String> {
+    let answers = html::between(html, "Your puzzle answer was <code>", "</code>");
+    answers.get(level.number() as usize - 1).map(|answer| html::strip_tags(answer))
+}
+
+// Posts an answer to `<base_url>/<year>/day/<day>/answer`, so tests can point it at a
+// local server instead of adventofcode.com.
+pub async fn submit_answer_at(
+    base_url: &str,
+    cookie: &SessionCookie,
+    year: u16,
+    day: u8,
+    level: Part,
+    answer: &str,
+) -> Result<Verdict, AocError> {
+    let url = format!("{}/{}/day/{}/answer", base_url.trim_end_matches('/'), year, day);
+    let level = level.number().to_string();
+
+    let response = Client::shared()
+        .post_form(&url, cookie, &[("level", level.as_str()), ("answer", answer)])
+        .await?;
+
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(error::classify_response(&url, status.as_u16(), None, &body).expired());
+    }
+
+    parse_verdict(&body).ok_or_else(|| AocError::Status {
+        status: status.as_u16(),
+        body: article_text(&body).chars().take(200).collect(),
+    })
+}
+
+// Submits an answer to adventofcode.com, logged in as `profile`.
+pub async fn submit_answer(profile: &Profile, year: u16, day: u8, level: Part, answer: &str) -> Result<Verdict, AocError> {
+    let cookie = profile.cookie().ok_or(AocError::NotLoggedIn)?;
+    submit_answer_at(crate::AOC_URL, cookie, year, day, level, answer).await
+}
+
+// Compares `answer` with what the ledger knows and, when `submit` is set and the
+// ledger can't tell whether it's right, submits it and records the verdict.
+// Answers the ledger already knows are never sent to the site again.
+pub async fn check_answer(
+    ledger: &mut Ledger,
+    profile: &Profile,
+    day: u8,
+    level: Part,
+    answer: &str,
+    submit: bool,
+) -> Result<Check, AocError> {
+    let check = ledger.check(crate::YEAR, day, level, answer);
+    match &check {
+        Check::Regression { expected } => {
+            return Err(AocError::Regression {
+                day,
+                part: level,
+                expected: expected.clone(),
+                actual: answer.to_string(),
+            })
+        }
+        Check::Correct => println!("{} matches the recorded correct answer", answer),
+        Check::KnownWrong(verdict) if submit => println!("not submitting {}, it was rejected before: {}", answer, verdict),
+        Check::KnownWrong(verdict) => println!("{} was rejected before: {}", answer, verdict),
+        Check::Unknown if submit => {
+            let verdict = submit_answer(profile, crate::YEAR, day, level, answer).await?;
+            println!("{}", verdict);
+            match verdict {
+                // A rate limited submission was never judged, so there is nothing to remember.
+                Verdict::RateLimited { .. } => {}
+                // Solved some other way, the ledger only learns the answer from the puzzle page.
+                Verdict::AlreadySolved => record_solved_answer(ledger, profile, day, level, answer).await?,
+                verdict => ledger.record(crate::YEAR, day, level, answer, verdict)?,
+            }
+        }
+        Check::Unknown => {}
+    }
+    Ok(check)
+}
+
+// Records the answer the puzzle page shows as correct, and fails like a
+// regression when `answer` isn't it.
+async fn record_solved_answer(ledger: &mut Ledger, profile: &Profile, day: u8, level: Part, answer: &str) -> Result<(), AocError> {
+    let page = crate::fetch_url(&crate::example::puzzle_url(day), profile.cookie()).await?;
+    let Some(correct) = parse_solved_answer(&page, level) else {
+        println!("the puzzle page doesn't show the answer to part {}, not recording it", level);
+        return Ok(());
+    };
+    ledger.record(crate::YEAR, day, level, &correct, Verdict::Correct)?;
+    if correct != answer {
+        return Err(AocError::Regression { day, part: level, expected: correct, actual: answer.to_string() });
+    }
+    println!("{} matches the answer on the puzzle page", answer);
+    Ok(())
+}
+
+// Checks `answer` against the ledger of `profile`, and submits it when the binary
+// was started with `--submit`. Answers for an input given with `--input` belong
+// to nobody's ledger and are left alone.
+pub async fn check_and_submit(profile: &Profile, day: u8, level: Part, answer: &str) -> Result<(), AocError> {
+    let args: Vec<String> = env::args().collect();
+    if crate::input_arg(&args).is_some() {
+        return Ok(());
+    }
+    let mut ledger = Ledger::load(profile.ledger_path())?;
+    let submit = args.iter().any(|arg| arg == "--submit");
+    check_answer(&mut ledger, profile, day, level, answer, submit).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    // Answers a single request with `html` and hands back the raw request it got.
+    fn serve_once(html: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 4096];
+            while !String::from_utf8_lossy(&request).contains("answer=") {
+                let n = stream.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                html.len(),
+                html
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8_lossy(&request).into_owned()
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = |text: &str| format!("<html><body><main><article><p>{}</p></article></main></body></html>", text);
+
+        assert_eq!(parse_verdict(&page("That's the right answer!  You are one gold star closer.")), Some(Verdict::Correct));
+        assert_eq!(
+            parse_verdict(&page("That's not the right answer; your answer is too high.  Please wait one minute.")),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(parse_verdict(&page("That's not the right answer; your answer is too low.")), Some(Verdict::TooLow));
+        assert_eq!(parse_verdict(&page("That's not the right answer.  If you're stuck...")), Some(Verdict::Wrong));
+        assert_eq!(
+            parse_verdict(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 30s left to wait.")),
+            Some(Verdict::RateLimited { wait: Duration::from_secs(90) })
+        );
+        assert_eq!(
+            parse_verdict(&page("You don't seem to be solving the right level.  Did you already complete it?")),
+            Some(Verdict::AlreadySolved)
+        );
+        assert_eq!(parse_verdict(&page("Something else entirely")), None);
+    }
+
+    #[test]
+    fn test_parse_solved_answer() {
+        let page = "<article class=\"day-desc\"><p>...</p></article>\n<p>Your puzzle answer was <code>54331</code>.</p>\n\
+                    <article class=\"day-desc\"><p>...</p></article>\n<p>Your puzzle answer was <code>54518</code>.</p>";
+        assert_eq!(parse_solved_answer(page, Part::One).as_deref(), Some("54331"));
+        assert_eq!(parse_solved_answer(page, Part::Two).as_deref(), Some("54518"));
+        assert_eq!(parse_solved_answer("<article><p>...</p></article>", Part::One), None);
+    }
+
+    #[tokio::test]
+    async fn test_submit_answer_at_local_server() {
+        let (base_url, server) = serve_once("<article><p>That's the right answer!</p></article>");
+
+        let cookie = SessionCookie::new("abc").unwrap();
+        let verdict = submit_answer_at(&base_url, &cookie, 2023, 4, Part::Two, "5704953").await.unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(verdict, Verdict::Correct);
+        assert!(request.starts_with("POST /2023/day/4/answer "));
+        assert!(request.contains("session=abc"));
+        assert!(request.contains("github.com/hitchhooker/aoc2023"));
+        assert!(request.contains("level=2&answer=5704953"));
+    }
+}
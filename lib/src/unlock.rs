@@ -0,0 +1,105 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::AocError;
+
+// Puzzles unlock at midnight US-Eastern, which is always EST (UTC-5) in December.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+// Since 2025 the event runs for 12 days instead of 25.
+pub fn days_in_year(year: u16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+// Days since 1970-01-01 of a proleptic Gregorian date (Howard Hinnant's days_from_civil).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// When the puzzle of `year`/`day` becomes available, or `None` if there is no such puzzle.
+pub fn unlock_time(year: u16, day: u8) -> Option<SystemTime> {
+    if year < 2015 || day == 0 || day > days_in_year(year) {
+        return None;
+    }
+    let days = days_from_civil(year as i64, 12, day as i64) as u64;
+    Some(UNIX_EPOCH + Duration::from_secs(days * 86_400 + UNLOCK_HOUR_UTC * 3_600))
+}
+
+// How long until the puzzle unlocks at `now`, zero once it is available.
+pub fn time_until_unlock(year: u16, day: u8, now: SystemTime) -> Option<Duration> {
+    let unlock = unlock_time(year, day)?;
+    Some(unlock.duration_since(now).unwrap_or(Duration::ZERO))
+}
+
+// Refuses to go near the server for a puzzle that isn't out yet.
+pub fn ensure_unlocked(year: u16, day: u8) -> Result<(), AocError> {
+    match time_until_unlock(year, day, SystemTime::now()) {
+        Some(remaining) if remaining.is_zero() => Ok(()),
+        Some(remaining) => Err(AocError::Locked { year, day, remaining }),
+        None => Err(AocError::NoSuchPuzzle { year, day }),
+    }
+}
+
+// Year and day of an adventofcode.com puzzle URL like `.../2023/day/4/input`.
+pub fn puzzle_of_url(url: &str) -> Option<(u16, u8)> {
+    let mut segments = url.split('/').skip_while(|s| s.parse::<u16>().is_err());
+    let year = segments.next()?.parse().ok()?;
+    if segments.next()? != "day" {
+        return None;
+    }
+    let day = segments.next()?.parse().ok()?;
+    Some((year, day))
+}
+
+pub fn format_remaining(d: Duration) -> String {
+    let secs = d.as_secs();
+    match (secs / 86_400, secs / 3_600 % 24, secs / 60 % 60, secs % 60) {
+        (0, 0, 0, s) => format!("{}s", s),
+        (0, 0, m, s) => format!("{}m {}s", m, s),
+        (0, h, m, _) => format!("{}h {}m", h, m),
+        (d, h, _, _) => format!("{}d {}h", d, h),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_time() {
+        // 2023-12-01T05:00:00Z
+        assert_eq!(unlock_time(2023, 1), Some(UNIX_EPOCH + Duration::from_secs(1_701_406_800)));
+        // 2023-12-25T05:00:00Z
+        assert_eq!(unlock_time(2023, 25), Some(UNIX_EPOCH + Duration::from_secs(1_703_480_400)));
+        assert_eq!(unlock_time(2023, 26), None);
+        assert_eq!(unlock_time(2025, 13), None);
+        assert_eq!(unlock_time(2014, 1), None);
+
+        let before = UNIX_EPOCH + Duration::from_secs(1_701_406_800 - 90);
+        assert_eq!(time_until_unlock(2023, 1, before), Some(Duration::from_secs(90)));
+        assert_eq!(time_until_unlock(2023, 1, SystemTime::now()), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_puzzle_of_url() {
+        assert_eq!(puzzle_of_url("https://adventofcode.com/2023/day/4/input"), Some((2023, 4)));
+        assert_eq!(puzzle_of_url("http://127.0.0.1:8080/2015/day/25"), Some((2015, 25)));
+        assert_eq!(puzzle_of_url("https://adventofcode.com/2023/leaderboard"), None);
+    }
+
+    #[test]
+    fn test_format_remaining() {
+        assert_eq!(format_remaining(Duration::from_secs(42)), "42s");
+        assert_eq!(format_remaining(Duration::from_secs(3 * 3600 + 12 * 60 + 5)), "3h 12m");
+        assert_eq!(format_remaining(Duration::from_secs(2 * 86_400 + 3600)), "2d 1h");
+    }
+}
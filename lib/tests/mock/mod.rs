@@ -0,0 +1,155 @@
+// In-process stand-in for adventofcode.com, serving what lib/fixtures holds:
+// `<year>/day/<day>/input`, `index.html` pages, `answer1`/`answer2` to judge
+// submissions against and private leaderboard JSON. Not every test binary uses
+// all of it.
+#![allow(dead_code)]
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// The only session the mock accepts, any other cookie is treated as expired.
+pub const SESSION: &str = "m0ck5e55101";
+
+const LOG_IN_TEXT: &str = "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+const UNLOCK_TEXT: &str = "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n";
+const LOGGED_OUT_PAGE: &str = "<!DOCTYPE html>\n<html lang=\"en-us\"><body><header><nav><ul><li><a href=\"/2023/auth/login\">[Log In]</a></li></ul></nav></header><main></main></body></html>\n";
+
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub user_agent: Option<String>,
+    pub body: String,
+}
+
+pub struct MockAoc {
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockAoc {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let log = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let log = Arc::clone(&log);
+                thread::spawn(move || handle(stream, &log));
+            }
+        });
+        MockAoc { base_url, requests }
+    }
+
+    // Every request served so far, oldest first.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+
+    let (mut cookie, mut user_agent, mut length) = (None, None, 0);
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).ok()?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(':')?;
+        match name.to_ascii_lowercase().as_str() {
+            "cookie" => cookie = Some(value.trim().to_string()),
+            "user-agent" => user_agent = Some(value.trim().to_string()),
+            "content-length" => length = value.trim().parse().ok()?,
+            _ => {}
+        }
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+    Some(Request { method, path, cookie, user_agent, body: String::from_utf8_lossy(&body).into_owned() })
+}
+
+fn form_value<'a>(body: &'a str, key: &str) -> Option<&'a str> {
+    body.split('&').find_map(|pair| pair.strip_prefix(key)?.strip_prefix('='))
+}
+
+fn verdict(year: &str, day: &str, body: &str) -> String {
+    let level = form_value(body, "level").unwrap_or("");
+    let answer = form_value(body, "answer").unwrap_or("");
+    let expected = fs::read_to_string(fixtures().join(year).join("day").join(day).join(format!("answer{}", level)));
+    let text = match expected.as_deref().map(str::trim) {
+        Err(_) => "You don't seem to be solving the right level.  Did you already complete it?",
+        Ok(expected) if expected == answer => "That's the right answer!  You are one gold star closer to restoring snow operations.",
+        Ok(expected) => match (answer.parse::<i64>(), expected.parse::<i64>()) {
+            (Ok(a), Ok(e)) if a > e => "That's not the right answer; your answer is too high.",
+            (Ok(a), Ok(e)) if a < e => "That's not the right answer; your answer is too low.",
+            _ => "That's not the right answer.",
+        },
+    };
+    format!("<html><body><main><article><p>{}</p></article></main></body></html>\n", text)
+}
+
+fn respond(request: &Request) -> (u16, String) {
+    let logged_in = request.cookie.as_deref() == Some(&format!("session={}", SESSION));
+    let file = |path: PathBuf| fs::read_to_string(path).ok();
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", [_, "day", _, "input"]) | ("POST", [_, "day", _, "answer"]) if !logged_in => (400, LOG_IN_TEXT.to_string()),
+        ("GET", [year, "day", day, "input"]) => match file(fixtures().join(year).join("day").join(day).join("input")) {
+            Some(input) => (200, input),
+            None => (404, UNLOCK_TEXT.to_string()),
+        },
+        ("POST", [year, "day", day, "answer"]) => (200, verdict(year, day, &request.body)),
+        // The site redirects to an HTML page when the leaderboard isn't ours to see.
+        ("GET", [_, "leaderboard", "private", "view", _]) if !logged_in => (200, LOGGED_OUT_PAGE.to_string()),
+        ("GET", [year]) if !logged_in && year.parse::<u16>().is_ok() => (200, LOGGED_OUT_PAGE.to_string()),
+        ("GET", _) => {
+            let path = fixtures().join(request.path.trim_matches('/'));
+            match file(path.join("index.html")).or_else(|| file(path)) {
+                Some(page) => (200, page),
+                None => (404, "404 Not Found\n".to_string()),
+            }
+        }
+        _ => (404, "404 Not Found\n".to_string()),
+    }
+}
+
+fn handle(mut stream: TcpStream, log: &Mutex<Vec<Request>>) {
+    let Some(request) = read_request(&stream) else { return };
+    let (status, body) = respond(&request);
+    log.lock().unwrap().push(request);
+
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        _ => "Not Found",
+    };
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
@@ -0,0 +1,119 @@
+mod mock;
+
+use std::fs;
+use std::path::PathBuf;
+
+use aoc2023::cache::Meta;
+use aoc2023::example::extract_examples;
+use aoc2023::leaderboard::get_leaderboard_at;
+use aoc2023::submit::submit_answer_at;
+use aoc2023::{AocError, Part, SessionCookie, Verdict};
+use mock::{MockAoc, SESSION};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("aoc2023-mock-{}-{}", std::process::id(), name))
+}
+
+fn session() -> SessionCookie {
+    SessionCookie::new(SESSION).unwrap()
+}
+
+#[tokio::test]
+async fn test_get_data_fetches_once_then_reads_the_cache() {
+    let server = MockAoc::start();
+    let path = temp_path("input.txt");
+    let path = path.to_str().unwrap();
+    let _ = fs::remove_file(path);
+    let url = format!("{}/2023/day/1/input", server.base_url);
+
+    let input = aoc2023::get_data_at(&url, Some(&session()), path).await.unwrap();
+    assert_eq!(input, "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n");
+    assert_eq!(aoc2023::get_data_at(&url, Some(&session()), path).await.unwrap(), input);
+    let meta = Meta::load(path.as_ref()).unwrap().unwrap();
+    assert_eq!((meta.status, meta.checksum), (200, aoc2023::cache::checksum(&input)));
+    fs::remove_file(path).unwrap();
+    fs::remove_file(aoc2023::cache::meta_path(path.as_ref())).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/2023/day/1/input");
+    assert!(requests[0].user_agent.as_deref().unwrap().starts_with("github.com/hitchhooker/aoc2023"));
+}
+
+#[tokio::test]
+async fn test_get_data_errors() {
+    let server = MockAoc::start();
+    let path = temp_path("errors.txt");
+    let path = path.to_str().unwrap();
+
+    let url = format!("{}/2023/day/1/input", server.base_url);
+    let expired = SessionCookie::new("0ld5e55101").unwrap();
+    let result = aoc2023::get_data_at(&url, Some(&expired), path).await;
+    assert!(matches!(result, Err(AocError::SessionExpired)), "{:?}", result);
+    assert!(matches!(aoc2023::get_data_at(&url, None, path).await, Err(AocError::NotLoggedIn)));
+
+    let url = format!("{}/2023/day/2/input", server.base_url);
+    let result = aoc2023::get_data_at(&url, Some(&session()), path).await;
+    assert!(matches!(result, Err(AocError::NotUnlocked { .. })), "{:?}", result);
+
+    // Nothing was cached, and the request without a cookie never left.
+    assert!(!std::path::Path::new(path).exists());
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_submit_answer_verdicts() {
+    let server = MockAoc::start();
+    let cookie = session();
+    let submit = |part, answer| submit_answer_at(&server.base_url, &cookie, 2023, 1, part, answer);
+
+    assert_eq!(submit(Part::One, "142").await.unwrap(), Verdict::Correct);
+    assert_eq!(submit(Part::One, "150").await.unwrap(), Verdict::TooHigh);
+    assert_eq!(submit(Part::Two, "200").await.unwrap(), Verdict::TooLow);
+    assert_eq!(submit(Part::Two, "abc").await.unwrap(), Verdict::Wrong);
+
+    let expired = SessionCookie::new("0ld5e55101").unwrap();
+    let result = submit_answer_at(&server.base_url, &expired, 2023, 1, Part::One, "142").await;
+    assert!(matches!(result, Err(AocError::SessionExpired)));
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].body, "level=1&answer=142");
+}
+
+#[tokio::test]
+async fn test_puzzle_page_and_session_check() {
+    let server = MockAoc::start();
+
+    let page = aoc2023::fetch_url(&format!("{}/2023/day/1", server.base_url), None).await.unwrap();
+    let examples = extract_examples(&page);
+    assert_eq!(examples.len(), 1);
+    assert_eq!(examples[0].answer.as_deref(), Some("142"));
+
+    let user = aoc2023::auth::check_session_at(&server.base_url, &session()).await.unwrap();
+    assert_eq!(user.name, "mock user");
+    assert_eq!(user.stars, Some(2));
+
+    let expired = SessionCookie::new("0ld5e55101").unwrap();
+    let result = aoc2023::auth::check_session_at(&server.base_url, &expired).await;
+    assert!(matches!(result, Err(AocError::SessionExpired)));
+}
+
+#[tokio::test]
+async fn test_leaderboard_is_cached() {
+    let server = MockAoc::start();
+    let path = temp_path("leaderboard.json");
+    let _ = fs::remove_file(&path);
+
+    let board = get_leaderboard_at(&server.base_url, &session(), 2023, 1001, &path).await.unwrap();
+    assert_eq!(board.ranked()[0].display_name(), "alice");
+    let again = get_leaderboard_at(&server.base_url, &session(), 2023, 1001, &path).await.unwrap();
+    assert_eq!(again, board);
+    assert_eq!(server.requests().len(), 1);
+
+    fs::remove_file(&path).unwrap();
+    let expired = SessionCookie::new("0ld5e55101").unwrap();
+    let result = get_leaderboard_at(&server.base_url, &expired, 2023, 1001, &path).await;
+    assert!(matches!(result, Err(AocError::SessionExpired)));
+    assert!(!path.exists());
+}
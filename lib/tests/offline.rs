@@ -0,0 +1,35 @@
+// Offline mode is a process-wide switch, so these get a test binary of their own.
+mod mock;
+
+use std::fs;
+
+use aoc2023::leaderboard::get_leaderboard_at;
+use aoc2023::{AocError, SessionCookie};
+use mock::{MockAoc, SESSION};
+
+#[tokio::test]
+async fn test_offline_never_touches_the_network() {
+    aoc2023::client::set_offline(true);
+    let server = MockAoc::start();
+    let session = SessionCookie::new(SESSION).unwrap();
+    let path = std::env::temp_dir().join(format!("aoc2023-offline-{}.txt", std::process::id()));
+    let path_str = path.to_str().unwrap();
+    let _ = fs::remove_file(&path);
+
+    let url = format!("{}/2023/day/1/input", server.base_url);
+    let result = aoc2023::get_data_at(&url, Some(&session), path_str).await;
+    assert!(matches!(result, Err(AocError::Offline { .. })), "{:?}", result);
+
+    aoc2023::save_to_file(path_str, "1abc2\n").unwrap();
+    assert_eq!(aoc2023::get_data_at(&url, Some(&session), path_str).await.unwrap(), "1abc2\n");
+
+    // A leaderboard cached long ago still beats no leaderboard at all.
+    fs::write(&path, include_str!("../fixtures/2023/leaderboard/private/view/1001.json")).unwrap();
+    let old = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+    fs::File::options().write(true).open(&path).unwrap().set_modified(old).unwrap();
+    let board = get_leaderboard_at(&server.base_url, &session, 2023, 1001, &path).await.unwrap();
+    assert_eq!(board.members.len(), 4);
+
+    fs::remove_file(&path).unwrap();
+    assert!(server.requests().is_empty());
+}